@@ -0,0 +1,252 @@
+//! Diagnostics for invalid `#[gusket]` attributes.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, Result};
+
+/// The position of a `#[gusket]` attribute.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Position {
+    Container,
+    Field,
+    Variant,
+}
+
+impl Position {
+    const ALL: [Position; 3] = [Position::Container, Position::Field, Position::Variant];
+
+    fn name(self) -> &'static str {
+        match self {
+            Position::Container => "container",
+            Position::Field => "field",
+            Position::Variant => "variant",
+        }
+    }
+
+    /// The attributes accepted at this position.
+    fn valid_attrs(self) -> &'static [&'static str] {
+        match self {
+            Position::Container => &[
+                "vis",
+                "immut",
+                "get",
+                "get_mut",
+                "set",
+                "all",
+                "chain",
+                "into",
+                "with",
+                "with_vis",
+                "get_vis",
+                "mut_vis",
+                "set_vis",
+                "replace",
+                "take",
+                "update",
+                "clone",
+                "deref",
+                "newtype",
+                "const",
+                "inline",
+                "must_use",
+                "track_caller",
+                "forward_attrs",
+                "auto_doc",
+                "doc",
+                "get_doc",
+                "mut_doc",
+                "set_doc",
+                "getter_prefix",
+                "getter_suffix",
+                "mut_prefix",
+                "mut_suffix",
+                "setter_prefix",
+                "setter_suffix",
+                "bool_prefix",
+                "deny_redundant",
+                "auto_copy",
+            ],
+            Position::Field => &[
+                "vis",
+                "immut",
+                "mut",
+                "get",
+                "get_mut",
+                "set",
+                "chain",
+                "into",
+                "with",
+                "with_vis",
+                "get_vis",
+                "mut_vis",
+                "set_vis",
+                "replace",
+                "take",
+                "update",
+                "copy",
+                "ref",
+                "clone",
+                "deref",
+                "option",
+                "const",
+                "inline",
+                "must_use",
+                "track_caller",
+                "forward_attrs",
+                "doc",
+                "get_doc",
+                "mut_doc",
+                "set_doc",
+                "skip",
+                "name",
+                "rename",
+                "getter",
+                "mut_getter",
+                "setter",
+                "shared",
+            ],
+            Position::Variant => &["vis", "immut", "mut", "skip"],
+        }
+    }
+}
+
+/// Reports an attribute that is not supported at `position`,
+/// suggesting a similar attribute or the position where it is supported.
+pub(crate) fn unsupported(ident: &syn::Ident, position: Position) -> Error {
+    let name = ident.to_string();
+    let valid = position.valid_attrs();
+
+    let mut message = match Position::ALL
+        .iter()
+        .find(|other| **other != position && other.valid_attrs().contains(&name.as_str()))
+    {
+        Some(other) => format!("`{}` is only supported as a {} attribute", name, other.name()),
+        None => match suggest(&name, valid) {
+            Some(suggestion) => {
+                format!("Unsupported attribute `{}`, did you mean `{}`?", name, suggestion)
+            }
+            None => format!("Unsupported attribute `{}`", name),
+        },
+    };
+
+    let valid: Vec<_> = valid.iter().map(|attr| format!("`{}`", attr)).collect();
+    message.push_str(&format!("\nValid {} attributes are {}", position.name(), valid.join(", ")));
+
+    Error::new_spanned(ident, message)
+}
+
+/// The accessors accepted by `#[gusket(option(...))]`.
+pub(crate) const OPTION_ACCESSORS: &[&str] =
+    &["take", "clear", "has", "get_or_insert_with", "into"];
+
+/// Reports an unknown `option(...)` accessor, suggesting a similar one.
+pub(crate) fn unsupported_option_accessor(flag: &syn::Ident) -> Error {
+    let name = flag.to_string();
+
+    let mut message = match suggest(&name, OPTION_ACCESSORS) {
+        Some(suggestion) => {
+            format!("Unsupported option accessor `{}`, did you mean `{}`?", name, suggestion)
+        }
+        None => format!("Unsupported option accessor `{}`", name),
+    };
+
+    let valid: Vec<_> = OPTION_ACCESSORS.iter().map(|flag| format!("`{}`", flag)).collect();
+    message.push_str(&format!("\nValid option accessors are {}", valid.join(", ")));
+
+    Error::new_spanned(flag, message)
+}
+
+/// Emits a warning pointing at `key`.
+///
+/// Proc macros cannot emit warnings on stable Rust,
+/// so this refers to a deprecated item with the span of `key` instead.
+pub(crate) fn warning(key: TokenStream, message: &str) -> TokenStream {
+    let span = key.into_iter().next().map_or_else(Span::call_site, |token| token.span());
+    let item = syn::Ident::new("redundant_gusket_attribute", span);
+    quote! {
+        const _: () = {
+            #[deprecated(note = #message)]
+            #[allow(non_camel_case_types)]
+            struct redundant_gusket_attribute;
+            let _ = #item;
+        };
+    }
+}
+
+/// Finds the most similar attribute name within a small edit distance.
+fn suggest<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let max_distance = name.chars().count() / 3 + 1;
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), *candidate))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Computes the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Pairs of attributes that contradict each other on the same item.
+const CONFLICTS: &[(&str, &str)] = &[
+    ("mut", "immut"),
+    ("name", "rename"),
+    ("copy", "ref"),
+    ("mut", "get"),
+    ("mut", "get_mut"),
+    ("mut", "set"),
+    ("immut", "get"),
+    ("immut", "get_mut"),
+    ("immut", "set"),
+];
+
+/// Detects repeated and conflicting attributes on the same item.
+#[derive(Default)]
+pub(crate) struct AttrTracker {
+    seen: Vec<String>,
+}
+
+impl AttrTracker {
+    /// Records an attribute identified by its leading tokens,
+    /// returning an error if it repeats or contradicts a previous attribute.
+    pub(crate) fn track(&mut self, key: TokenStream) -> Result<()> {
+        let name = key.to_string();
+
+        if self.seen.contains(&name) {
+            return Err(Error::new_spanned(key, format!("Duplicate attribute `{}`", name)));
+        }
+
+        // Attributes other than `vis` would be silently ignored on a skipped item.
+        let conflict = self.seen.iter().find(|other| {
+            (name == "skip" && other.as_str() != "vis")
+                || (other.as_str() == "skip" && name != "vis")
+                || CONFLICTS.iter().any(|&(a, b)| {
+                    (name == a && other.as_str() == b) || (name == b && other.as_str() == a)
+                })
+        });
+        if let Some(other) = conflict {
+            return Err(Error::new_spanned(
+                key,
+                format!("`{}` cannot be combined with `{}`", name, other),
+            ));
+        }
+
+        self.seen.push(name);
+        Ok(())
+    }
+}
@@ -0,0 +1,396 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{Error, Result};
+
+use crate::diagnostics::{self, AttrTracker, Position};
+use crate::{
+    is_forwarded, parse_attr_list, summarized_docs, Errors, FieldAccess, FieldOptions, InputAttrs,
+};
+
+/// Generates `is_`, `as_`, `as_*_mut` and `into_` methods for an enum variant.
+pub(crate) fn process_variant(
+    variant: &syn::Variant,
+    variant_count: usize,
+    input_attrs: &InputAttrs,
+    methods: &mut TokenStream,
+    errors: &mut Errors,
+) {
+    let variant_ident = &variant.ident;
+
+    let mut variant_vis = input_attrs.vis.clone();
+    let mut derive = input_attrs.derive;
+    let mut mutable = input_attrs.accessors.get_mut;
+
+    let mut docs = Vec::new();
+    let mut attrs = Vec::new();
+    let mut tracker = AttrTracker::default();
+    let mut vis_ident = None;
+    let mut skip = None;
+
+    for attr in &variant.attrs {
+        if attr.path.is_ident("gusket") {
+            derive = true;
+
+            if !attr.tokens.is_empty() {
+                for attr in parse_attr_list::<VariantAttr>(attr, errors) {
+                    if errors.ok(tracker.track(attr.key())).is_none() {
+                        continue;
+                    }
+
+                    match attr {
+                        VariantAttr::Vis(ident, vis) => {
+                            variant_vis = vis;
+                            vis_ident = Some(ident);
+                        }
+                        VariantAttr::Immut(ident) => {
+                            if !input_attrs.accessors.get_mut {
+                                errors.redundant(
+                                    quote!(#ident),
+                                    "`immut` has no effect because the container is already \
+                                     immutable",
+                                    input_attrs,
+                                );
+                            }
+                            mutable = false;
+                        }
+                        VariantAttr::Mut(_) => mutable = true,
+                        VariantAttr::Skip(ident) => skip = Some(ident),
+                    }
+                }
+            }
+        } else if attr.path.is_ident("doc") {
+            docs.push(attr);
+        } else if is_forwarded(attr, input_attrs.forward_attrs) {
+            attrs.push(attr);
+        }
+    }
+
+    if let Some(skip) = skip {
+        if let Some(vis_ident) = vis_ident {
+            errors.redundant(
+                quote!(#vis_ident),
+                "`vis` has no effect on a skipped variant",
+                input_attrs,
+            );
+        }
+        if !input_attrs.derive {
+            errors.redundant(
+                quote!(#skip),
+                "`skip` has no effect because the container does not derive all variants",
+                input_attrs,
+            );
+        }
+        return;
+    }
+    if !derive {
+        return;
+    }
+
+    let inline = input_attrs.inline.attr();
+    let must_use_getter = input_attrs.must_use.attr("Getters have no side effect");
+    let must_use_mut_getter = input_attrs.must_use.attr("Mutable getters have no side effect");
+    let must_use_into = input_attrs.must_use.attr("Consuming the enum has no side effect");
+
+    let method_docs =
+        |summary: String| summarized_docs(input_attrs.auto_doc.then_some(summary.as_str()), &docs);
+
+    let snake = to_snake_case(&variant_ident.to_string());
+    let is_fn = format_ident!("is_{}", snake, span = variant_ident.span());
+
+    let is_docs =
+        method_docs(format!("Returns `true` if `self` is the `{}` variant.", variant_ident));
+    methods.extend(quote_spanned! { variant.span() =>
+        #is_docs
+        #(#attrs)*
+        #must_use_getter
+        #inline
+        #variant_vis fn #is_fn(&self) -> bool {
+            ::core::matches!(self, Self::#variant_ident { .. })
+        }
+    });
+
+    if variant.fields.is_empty() {
+        return;
+    }
+
+    let as_fn = format_ident!("as_{}", snake, span = variant_ident.span());
+    let as_mut_fn = format_ident!("as_{}_mut", snake, span = variant_ident.span());
+    let into_fn = format_ident!("into_{}", snake, span = variant_ident.span());
+
+    let bindings: Vec<syn::Ident> = variant
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field_{}", index, span = field.span()),
+        })
+        .collect();
+    let pattern = match &variant.fields {
+        syn::Fields::Named(_) => quote!(Self::#variant_ident { #(#bindings),* }),
+        _ => quote!(Self::#variant_ident(#(#bindings),*)),
+    };
+
+    let tys: Vec<_> = variant.fields.iter().map(|field| &field.ty).collect();
+    let (ref_ty, mut_ty, owned_ty, value) = if bindings.len() == 1 {
+        let ty = tys[0];
+        let binding = &bindings[0];
+        (quote!(&#ty), quote!(&mut #ty), quote!(#ty), quote!(#binding))
+    } else {
+        (
+            quote!((#(&#tys),*)),
+            quote!((#(&mut #tys),*)),
+            quote!((#(#tys),*)),
+            quote!((#(#bindings),*)),
+        )
+    };
+
+    // A catch-all arm would be unreachable if this is the only variant.
+    let (none_arm, err_arm) = if variant_count > 1 {
+        (
+            quote!(_ => ::core::option::Option::None,),
+            quote!(other => ::core::result::Result::Err(other),),
+        )
+    } else {
+        (quote!(), quote!())
+    };
+
+    let as_docs = method_docs(format!(
+        "Returns a reference to the contents of the `{}` variant, if `self` is that variant.",
+        variant_ident
+    ));
+    methods.extend(quote_spanned! { variant.span() =>
+        #as_docs
+        #(#attrs)*
+        #must_use_getter
+        #inline
+        #variant_vis fn #as_fn(&self) -> ::core::option::Option<#ref_ty> {
+            match self {
+                #pattern => ::core::option::Option::Some(#value),
+                #none_arm
+            }
+        }
+    });
+
+    if mutable {
+        let as_mut_docs = method_docs(format!(
+            "Returns a mutable reference to the contents of the `{}` variant, if `self` is that \
+             variant.",
+            variant_ident
+        ));
+        methods.extend(quote_spanned! { variant.span() =>
+            #as_mut_docs
+            #(#attrs)*
+            #must_use_mut_getter
+            #inline
+            #variant_vis fn #as_mut_fn(&mut self) -> ::core::option::Option<#mut_ty> {
+                match self {
+                    #pattern => ::core::option::Option::Some(#value),
+                    #none_arm
+                }
+            }
+        });
+    }
+
+    let into_docs = method_docs(format!(
+        "Returns the contents of the `{}` variant, or `self` if it is another variant.",
+        variant_ident
+    ));
+    methods.extend(quote_spanned! { variant.span() =>
+        #into_docs
+        #(#attrs)*
+        #must_use_into
+        #inline
+        #variant_vis fn #into_fn(self) -> ::core::result::Result<#owned_ty, Self> {
+            match self {
+                #pattern => ::core::result::Result::Ok(#value),
+                #err_arm
+            }
+        }
+    });
+}
+
+/// Generates accessors for fields marked `#[gusket(shared)]`,
+/// which must be present with the same type in every variant.
+pub(crate) fn process_shared_fields(
+    data: &syn::DataEnum,
+    input_attrs: &InputAttrs,
+    methods: &mut TokenStream,
+    errors: &mut Errors,
+) {
+    let mut shared_fields: Vec<(&syn::Variant, FieldOptions)> = Vec::new();
+
+    for variant in &data.variants {
+        for (index, field) in variant.fields.iter().enumerate() {
+            if !field.attrs.iter().any(|attr| attr.path.is_ident("gusket")) {
+                continue;
+            }
+
+            let options = match FieldOptions::parse(field, index, input_attrs, errors) {
+                Some(options) => options,
+                None => continue,
+            };
+            let shared = match &options.shared {
+                Some(shared) => shared,
+                None => {
+                    errors.push(Error::new_spanned(
+                        field,
+                        "Fields of enum variants can only be derived with `shared`",
+                    ));
+                    continue;
+                }
+            };
+            let ident = match &field.ident {
+                Some(ident) => ident,
+                None => {
+                    errors.push(Error::new_spanned(
+                        shared,
+                        "`shared` is only supported on named fields",
+                    ));
+                    continue;
+                }
+            };
+
+            if let Some((other_variant, _)) =
+                shared_fields.iter().find(|(_, other)| other.field.ident.as_ref() == Some(ident))
+            {
+                errors.push(Error::new_spanned(
+                    shared,
+                    format!(
+                        "Shared field `{}` is already configured in variant `{}`",
+                        ident, &other_variant.ident
+                    ),
+                ));
+                continue;
+            }
+
+            shared_fields.push((variant, options));
+        }
+    }
+
+    // Mixed-site hygiene prevents the binding from shadowing the setter parameter.
+    let binding = syn::Ident::new("field", Span::mixed_site());
+
+    for (_, options) in &shared_fields {
+        let ident = options.field.ident.as_ref().expect("Shared fields are named");
+        let expect_ty = &options.field.ty;
+        let expect_ty_string = quote!(#expect_ty).to_string();
+
+        // The accessors cannot be generated unless every variant has a matching field.
+        let mut patterns = Vec::new();
+        let mut complete = true;
+        for variant in &data.variants {
+            let variant_ident = &variant.ident;
+
+            let field = variant.fields.iter().find(|field| field.ident.as_ref() == Some(ident));
+            let field = match field {
+                Some(field) => field,
+                None => {
+                    errors.push(Error::new_spanned(
+                        variant_ident,
+                        format!(
+                            "Variant `{}` is missing the shared field `{}`",
+                            variant_ident, ident
+                        ),
+                    ));
+                    complete = false;
+                    continue;
+                }
+            };
+
+            let ty = &field.ty;
+            if quote!(#ty).to_string() != expect_ty_string {
+                errors.push(Error::new_spanned(
+                    ty,
+                    format!(
+                        "Shared field `{}` must have type `{}` in every variant",
+                        ident, expect_ty_string
+                    ),
+                ));
+                complete = false;
+                continue;
+            }
+
+            patterns.push(quote!(Self::#variant_ident { #ident: #binding, .. }));
+        }
+
+        if complete {
+            options.emit(
+                &FieldAccess::Variants { patterns, binding: binding.clone() },
+                methods,
+                errors,
+            );
+        }
+    }
+}
+
+/// Converts an `UpperCamelCase` variant name into `snake_case`.
+fn to_snake_case(name: &str) -> String {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    let chars: Vec<char> = name.chars().collect();
+
+    let mut output = String::new();
+    for (i, &ch) in chars.iter().enumerate() {
+        if ch.is_uppercase() {
+            let prev = if i > 0 { chars.get(i - 1) } else { None };
+            let next = chars.get(i + 1);
+            let boundary = match prev {
+                None | Some('_') => false,
+                Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+                Some(_) => next.is_some_and(|next| next.is_lowercase()),
+            };
+            if boundary {
+                output.push('_');
+            }
+            output.extend(ch.to_lowercase());
+        } else {
+            output.push(ch);
+        }
+    }
+    output
+}
+
+enum VariantAttr {
+    Vis(syn::Ident, syn::Visibility),
+    Immut(syn::Ident),
+    Mut(syn::Token![mut]),
+    Skip(syn::Ident),
+}
+
+impl VariantAttr {
+    /// Returns the leading tokens identifying the attribute.
+    fn key(&self) -> TokenStream {
+        match self {
+            VariantAttr::Mut(token) => quote!(#token),
+            VariantAttr::Vis(ident, _) | VariantAttr::Immut(ident) | VariantAttr::Skip(ident) => {
+                quote!(#ident)
+            }
+        }
+    }
+}
+
+impl Parse for VariantAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(syn::Token![mut]) {
+            let mut_token: syn::Token![mut] = input.parse()?;
+            return Ok(Self::Mut(mut_token));
+        }
+
+        // Keywords such as `ref` and `const` are parsed as identifiers to report their position.
+        let ident = input.call(syn::Ident::parse_any)?;
+        if ident == "vis" {
+            input.parse::<syn::Token![=]>()?;
+            let vis: syn::Visibility = input.parse()?;
+            Ok(Self::Vis(ident, vis))
+        } else if ident == "immut" {
+            Ok(Self::Immut(ident))
+        } else if ident == "skip" {
+            Ok(Self::Skip(ident))
+        } else {
+            Err(diagnostics::unsupported(&ident, Position::Variant))
+        }
+    }
+}
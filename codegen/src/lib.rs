@@ -1,30 +1,40 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{Error, Result};
 
+use crate::diagnostics::{AttrTracker, Position};
+
+mod diagnostics;
+mod enums;
 mod tests;
+mod types;
 
 #[proc_macro_derive(Gusket, attributes(gusket))]
 pub fn gusket(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    match gusket_impl(ts.into()) {
-        Ok(output) => output,
-        Err(err) => err.into_compile_error(),
-    }
-    .into()
+    gusket_impl(ts.into()).into()
 }
 
-fn gusket_impl(ts: TokenStream) -> Result<TokenStream> {
-    let input = syn::parse2::<syn::DeriveInput>(ts)?;
+/// Generates the accessors of a type.
+///
+/// All attribute errors are reported together with the methods that could still be generated,
+/// so that a typo does not cascade into missing method errors downstream.
+fn gusket_impl(ts: TokenStream) -> TokenStream {
+    let input = match syn::parse2::<syn::DeriveInput>(ts) {
+        Ok(input) => input,
+        Err(err) => return err.into_compile_error(),
+    };
     let input_ident = &input.ident;
 
+    let mut errors = Errors::default();
     let mut input_attrs = InputAttrs::new(&input.vis);
 
     for attr in &input.attrs {
         if attr.path.is_ident("gusket") {
-            input_attrs.apply(attr)?;
+            errors.ok(input_attrs.apply(attr));
         }
     }
 
@@ -48,165 +58,1625 @@ fn gusket_impl(ts: TokenStream) -> Result<TokenStream> {
     };
     let generics_where = &input.generics.where_clause;
 
-    let data = match &input.data {
-        syn::Data::Struct(data) => data,
+    let mut methods = TokenStream::new();
+    let mut newtype_field = None;
+    let mut newtype_mutable = false;
+
+    match &input.data {
+        syn::Data::Struct(data) => {
+            let fields = match &data.fields {
+                syn::Fields::Named(fields) => &fields.named,
+                syn::Fields::Unnamed(fields) => &fields.unnamed,
+                syn::Fields::Unit => {
+                    errors.push(Error::new_spanned(
+                        data.semi_token,
+                        "Unit structs are not supported",
+                    ));
+                    return errors.into_compile_error();
+                }
+            };
+
+            if let Some(newtype) = input_attrs.newtype.take() {
+                match &data.fields {
+                    syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        newtype_field = fields.unnamed.first();
+                        input_attrs.newtype = Some(newtype);
+                    }
+                    _ => errors.push(Error::new_spanned(
+                        newtype,
+                        "`newtype` is only supported on tuple structs with exactly one field",
+                    )),
+                }
+            }
+
+            for (index, field) in fields.iter().enumerate() {
+                let options =
+                    process_field(field, index, false, &input_attrs, &mut methods, &mut errors);
+                // `AsMut` must not bypass an `immut` or skipped newtype field.
+                newtype_mutable = options.is_some_and(|options| options.accessors.get_mut);
+            }
+        }
         syn::Data::Enum(data) => {
-            return Err(Error::new_spanned(&data.enum_token, "Enums are not supported"));
+            if let Some(newtype) = input_attrs.newtype.take() {
+                errors.push(Error::new_spanned(newtype, "`newtype` is not supported on enums"));
+            }
+
+            for variant in &data.variants {
+                enums::process_variant(
+                    variant,
+                    data.variants.len(),
+                    &input_attrs,
+                    &mut methods,
+                    &mut errors,
+                );
+            }
+            enums::process_shared_fields(data, &input_attrs, &mut methods, &mut errors);
         }
         syn::Data::Union(data) => {
-            return Err(Error::new_spanned(&data.union_token, "Unions are not supported"));
+            if let Some(newtype) = input_attrs.newtype.take() {
+                errors.push(Error::new_spanned(newtype, "`newtype` is not supported on unions"));
+            }
+
+            for (index, field) in data.fields.named.iter().enumerate() {
+                process_field(field, index, true, &input_attrs, &mut methods, &mut errors);
+            }
+        }
+    }
+
+    let mut output = quote! {
+        impl #generics_decl #input_ident #generics_usage #generics_where {
+            #methods
         }
     };
 
-    let named = match &data.fields {
-        syn::Fields::Named(fields) => fields,
-        syn::Fields::Unnamed(fields) => {
-            return Err(Error::new(fields.paren_token.span, "Tuple structs are not supported"));
+    if let Some(field) = newtype_field {
+        let self_ty = quote!(#input_ident #generics_usage);
+        let impl_prefix = quote!(impl #generics_decl);
+        output.extend(newtype_impls(
+            field,
+            newtype_mutable,
+            &input.generics,
+            &impl_prefix,
+            &self_ty,
+            generics_where,
+            &input_attrs,
+        ));
+    }
+
+    output.extend(errors.into_compile_error());
+    output
+}
+
+/// Collects errors and warnings so that all of them are reported in a single compilation.
+#[derive(Default)]
+struct Errors {
+    error:    Option<Error>,
+    warnings: TokenStream,
+}
+
+impl Errors {
+    fn push(&mut self, err: Error) {
+        match &mut self.error {
+            Some(errors) => errors.combine(err),
+            None => self.error = Some(err),
         }
-        syn::Fields::Unit => {
-            return Err(Error::new_spanned(&data.semi_token, "Tuple structs are not supported"));
+    }
+
+    /// Reports an attribute that has no effect,
+    /// as an error if the container is `#[gusket(deny_redundant)]`.
+    fn redundant(&mut self, key: TokenStream, message: &str, input_attrs: &InputAttrs) {
+        if input_attrs.deny_redundant {
+            self.push(Error::new_spanned(key, message));
+        } else {
+            self.warnings.extend(diagnostics::warning(key, message));
         }
-    };
+    }
 
-    let mut methods = TokenStream::new();
+    /// Returns the value of `result`, recording its error otherwise.
+    fn ok<T>(&mut self, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.push(err);
+                None
+            }
+        }
+    }
 
-    for field in &named.named {
-        process_field(field, &input_attrs, &mut methods)?;
+    /// Returns the collected errors, discarding the warnings.
+    fn finish(self) -> Result<()> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
     }
 
-    let output = quote! {
-        impl #generics_decl #input_ident #generics_usage #generics_where {
-            #methods
+    fn into_compile_error(self) -> TokenStream {
+        let mut output = self.warnings;
+        if let Some(err) = self.error {
+            output.extend(err.into_compile_error());
+        }
+        output
+    }
+}
+
+/// Parses the comma-separated items of a `#[gusket(...)]` attribute,
+/// skipping to the next item when an item is invalid.
+fn parse_attr_list<T: Parse>(attr: &syn::Attribute, errors: &mut Errors) -> Vec<T> {
+    let parser = |input: ParseStream| {
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            let item = input.parse().and_then(|item| {
+                if input.is_empty() || input.peek(syn::Token![,]) {
+                    Ok(item)
+                } else {
+                    Err(input.error("expected `,`"))
+                }
+            });
+            match item {
+                Ok(item) => items.push(item),
+                Err(err) => {
+                    errors.push(err);
+                    input.step(|cursor| {
+                        let mut rest = *cursor;
+                        while let Some((tt, next)) = rest.token_tree() {
+                            match tt {
+                                TokenTree::Punct(punct) if punct.as_char() == ',' => break,
+                                _ => rest = next,
+                            }
+                        }
+                        Ok(((), rest))
+                    })?;
+                }
+            }
+
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
         }
+        Ok(items)
     };
 
-    Ok(output)
+    let result = attr.parse_args_with(parser);
+    errors.ok(result).unwrap_or_default()
 }
 
-fn process_field(
+/// Generates `into_inner()` and the conversion trait implementations for `#[gusket(newtype)]`.
+fn newtype_impls(
     field: &syn::Field,
+    mutable: bool,
+    generics: &syn::Generics,
+    impl_prefix: &TokenStream,
+    self_ty: &TokenStream,
+    generics_where: &Option<syn::WhereClause>,
+    input_attrs: &InputAttrs,
+) -> TokenStream {
+    let inner_ty = &field.ty;
+    let vis = &input_attrs.vis;
+    let inline = input_attrs.inline.attr();
+    let must_use = input_attrs.must_use.attr("Consuming the newtype has no side effect");
+    let docs = summarized_docs(
+        input_attrs.auto_doc.then_some("Returns the inner value, consuming the newtype."),
+        &[],
+    );
+
+    let mut output = quote_spanned! { field.span() =>
+        #impl_prefix #self_ty #generics_where {
+            #docs
+            #must_use
+            #inline
+            #vis fn into_inner(self) -> #inner_ty {
+                self.0
+            }
+        }
+
+        #impl_prefix ::core::convert::AsRef<#inner_ty> for #self_ty #generics_where {
+            #inline
+            fn as_ref(&self) -> &#inner_ty {
+                &self.0
+            }
+        }
+
+        #impl_prefix ::core::convert::From<#inner_ty> for #self_ty #generics_where {
+            #inline
+            fn from(inner: #inner_ty) -> Self {
+                Self(inner)
+            }
+        }
+    };
+
+    if mutable {
+        output.extend(quote_spanned! { field.span() =>
+            #impl_prefix ::core::convert::AsMut<#inner_ty> for #self_ty #generics_where {
+                #inline
+                fn as_mut(&mut self) -> &mut #inner_ty {
+                    &mut self.0
+                }
+            }
+        });
+    }
+
+    // `impl<T> From<Wrapper<T>> for T` and `for Box<T>` violate the orphan rules,
+    // so the reverse conversion is only generated if every type parameter is covered.
+    if !types::has_uncovered_param(inner_ty, generics) {
+        output.extend(quote_spanned! { field.span() =>
+            #impl_prefix ::core::convert::From<#self_ty> for #inner_ty #generics_where {
+                #inline
+                fn from(value: #self_ty) -> Self {
+                    value.0
+                }
+            }
+        });
+    }
+
+    output
+}
+
+/// Generates the accessors of a field,
+/// returning the resolved options or `None` if no accessors are derived.
+fn process_field<'t>(
+    field: &'t syn::Field,
+    index: usize,
+    is_union: bool,
     input_attrs: &InputAttrs,
     methods: &mut TokenStream,
-) -> Result<()> {
-    let field_ident = field.ident.as_ref().expect("Struct is named");
-    let field_ty = &field.ty;
+    errors: &mut Errors,
+) -> Option<FieldOptions<'t>> {
+    let mut options = FieldOptions::parse(field, index, input_attrs, errors)?;
 
-    let mut field_vis = input_attrs.vis.clone();
-    let mut is_copy = None;
-    let mut derive = input_attrs.derive;
-    let mut mutable = input_attrs.mutable;
+    if let Some(shared) = &options.shared {
+        errors.push(Error::new_spanned(
+            shared,
+            "`shared` is only supported on fields of enum variants",
+        ));
+    }
 
-    let mut docs = Vec::new();
+    let member = match &field.ident {
+        Some(ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index { index: index as u32, span: field.span() }),
+    };
 
-    for attr in &field.attrs {
-        if attr.path.is_ident("gusket") {
+    if is_union {
+        if let Some(option) = options.option.take() {
+            errors.push(Error::new_spanned(
+                &option.ident,
+                "`option` is not supported on union fields",
+            ));
+        }
+    }
+
+    let access =
+        if is_union { FieldAccess::UnionMember(member) } else { FieldAccess::Member(member) };
+    options.emit(&access, methods, errors);
+    Some(options)
+}
+
+/// The accessor configuration of a field after applying container and field attributes.
+struct FieldOptions<'t> {
+    field:         &'t syn::Field,
+    index:         usize,
+    name:          Option<syn::Ident>,
+    getter:        Option<syn::Ident>,
+    mut_getter:    Option<syn::Ident>,
+    setter:        Option<syn::Ident>,
+    naming:        Naming,
+    get_vis:       syn::Visibility,
+    mut_vis:       syn::Visibility,
+    set_vis:       syn::Visibility,
+    is_copy:       Option<syn::Ident>,
+    clone:         Option<syn::Ident>,
+    deref:         Option<TokenStream>,
+    option:        Option<OptionField<'t>>,
+    constness:     bool,
+    inline:        Inline,
+    must_use:      MustUse,
+    track_caller:  bool,
+    accessors:     Accessors,
+    chain:         bool,
+    into:          bool,
+    with:          bool,
+    with_vis:      syn::Visibility,
+    replace:       bool,
+    take:          bool,
+    update:        bool,
+    shared:        Option<syn::Ident>,
+    docs:          Vec<&'t syn::Attribute>,
+    auto_doc:      bool,
+    doc_templates: DocTemplates,
+    /// Non-doc attributes forwarded to all generated methods.
+    attrs:         Vec<&'t syn::Attribute>,
+}
+
+/// A field of type `Option<T>` with `#[gusket(option)]`.
+struct OptionField<'t> {
+    ident:     syn::Ident,
+    inner:     &'t syn::Type,
+    accessors: OptionAccessors,
+}
+
+impl<'t> FieldOptions<'t> {
+    /// Returns `None` if no accessors should be derived for the field.
+    ///
+    /// Invalid options are reported to `errors` and ignored.
+    fn parse(
+        field: &'t syn::Field,
+        index: usize,
+        input_attrs: &InputAttrs,
+        errors: &mut Errors,
+    ) -> Option<Self> {
+        let mut name = field.ident.clone();
+        let mut getter = None;
+        let mut mut_getter = None;
+        let mut setter = None;
+        let mut field_vis = input_attrs.vis.clone();
+        let mut is_copy = None;
+        let mut ref_token = None;
+        let mut clone = None;
+        let mut deref = None;
+        let mut option = None;
+        let mut const_token = None;
+        let mut inline = input_attrs.inline;
+        let mut must_use = input_attrs.must_use.clone();
+        let mut track_caller = input_attrs.track_caller;
+        let mut derive = input_attrs.derive;
+        let mut shared = None;
+        let mut vis_ident = None;
+        let mut skip = None;
+
+        if input_attrs.newtype.is_some() {
+            name = Some(format_ident!("inner"));
             derive = true;
+        }
+        let mut accessors = input_attrs.accessors;
+        let mut selecting = false;
+        let mut chain = input_attrs.chain;
+        let mut into = input_attrs.into;
+        let mut replace = input_attrs.replace;
+        let mut take = input_attrs.take;
+        let mut update = input_attrs.update;
+        let mut with = input_attrs.with;
+        let mut get_vis = None;
+        let mut mut_vis = None;
+        let mut set_vis = None;
+        let mut with_vis = None;
+
+        let mut forward_attrs = input_attrs.forward_attrs;
+        let mut doc_templates = DocTemplates::default();
+
+        let mut docs = Vec::new();
+        let mut other_attrs = Vec::new();
+        let mut tracker = AttrTracker::default();
+
+        for attr in &field.attrs {
+            if attr.path.is_ident("gusket") {
+                derive = true;
+
+                if !attr.tokens.is_empty() {
+                    for attr in parse_attr_list::<FieldAttr>(attr, errors) {
+                        if errors.ok(tracker.track(attr.key())).is_none() {
+                            continue;
+                        }
 
-            if !attr.tokens.is_empty() {
-                let attr_list: Punctuated<FieldAttr, syn::Token![,]> =
-                    attr.parse_args_with(Punctuated::parse_terminated)?;
-                for attr in attr_list {
-                    match attr {
-                        FieldAttr::Vis(_, vis) => field_vis = vis,
-                        FieldAttr::Immut(_) => mutable = false,
-                        FieldAttr::Mut(_) => mutable = true,
-                        FieldAttr::Copy(ident) => is_copy = Some(ident),
-                        FieldAttr::Skip(_) => derive = false,
+                        match attr {
+                            FieldAttr::Vis(ident, vis) => {
+                                field_vis = vis;
+                                vis_ident = Some(ident);
+                            }
+                            FieldAttr::Immut(ident) => {
+                                if !input_attrs.accessors.get_mut && !input_attrs.accessors.set {
+                                    errors.redundant(
+                                        quote!(#ident),
+                                        "`immut` has no effect because the container is already \
+                                         immutable",
+                                        input_attrs,
+                                    );
+                                }
+                                accessors.set_mutable(false);
+                            }
+                            FieldAttr::Mut(_) => accessors.set_mutable(true),
+                            FieldAttr::Get(_) => accessors.select(&mut selecting).get = true,
+                            FieldAttr::GetMut(_) => accessors.select(&mut selecting).get_mut = true,
+                            FieldAttr::Set(_) => accessors.select(&mut selecting).set = true,
+                            FieldAttr::Chain(_) => chain = true,
+                            FieldAttr::Into(_) => into = true,
+                            FieldAttr::Replace(_) => replace = true,
+                            FieldAttr::Take(_) => take = true,
+                            FieldAttr::Update(_) => update = true,
+                            FieldAttr::With(_) => with = true,
+                            FieldAttr::GetVis(_, vis) => get_vis = Some(vis),
+                            FieldAttr::MutVis(_, vis) => mut_vis = Some(vis),
+                            FieldAttr::SetVis(_, vis) => set_vis = Some(vis),
+                            FieldAttr::WithVis(_, vis) => with_vis = Some(vis),
+                            FieldAttr::Copy(ident) => is_copy = Some(ident),
+                            FieldAttr::Ref(token) => ref_token = Some(token),
+                            FieldAttr::Clone(ident) => clone = Some(ident),
+                            FieldAttr::Deref(ident, target) => deref = Some((ident, target)),
+                            FieldAttr::Option(ident, accessors) => {
+                                option = Some((ident, accessors))
+                            }
+                            FieldAttr::Const(token) => const_token = Some(token),
+                            FieldAttr::Inline(_, policy) => inline = policy,
+                            FieldAttr::MustUse(_, policy) => must_use = policy,
+                            FieldAttr::TrackCaller(_) => track_caller = true,
+                            FieldAttr::ForwardAttrs(_) => forward_attrs = true,
+                            FieldAttr::DocTemplate(ident, template) => {
+                                doc_templates.set(&ident, template.value())
+                            }
+                            FieldAttr::Skip(ident) => skip = Some(ident),
+                            FieldAttr::Name(ident, new_name) => {
+                                if field.ident.is_some() {
+                                    errors.push(Error::new_spanned(
+                                        ident,
+                                        "`name` is only supported on tuple struct fields, use \
+                                         `rename` instead",
+                                    ));
+                                } else {
+                                    name = Some(new_name);
+                                }
+                            }
+                            FieldAttr::Rename(_, new_name) => name = Some(new_name),
+                            FieldAttr::Getter(_, ident) => getter = Some(ident),
+                            FieldAttr::MutGetter(_, ident) => mut_getter = Some(ident),
+                            FieldAttr::Setter(_, ident) => setter = Some(ident),
+                            FieldAttr::Shared(ident) => shared = Some(ident),
+                        }
                     }
                 }
+            } else if attr.path.is_ident("doc") {
+                docs.push(attr);
+            } else {
+                other_attrs.push(attr);
+            }
+        }
+
+        if let Some(skip) = skip {
+            if let Some(vis_ident) = vis_ident {
+                errors.redundant(
+                    quote!(#vis_ident),
+                    "`vis` has no effect on a skipped field",
+                    input_attrs,
+                );
+            }
+            if !input_attrs.derive && input_attrs.newtype.is_none() {
+                errors.redundant(
+                    quote!(#skip),
+                    "`skip` has no effect because the container does not derive all fields",
+                    input_attrs,
+                );
+            }
+            return None;
+        }
+        if !derive {
+            return None;
+        }
+
+        let attrs =
+            other_attrs.into_iter().filter(|attr| is_forwarded(attr, forward_attrs)).collect();
+        let doc_templates = doc_templates.resolve(&input_attrs.doc_templates);
+
+        // `auto_copy` returns recognized `Copy` types by value unless the field opts out with `ref`.
+        match (&ref_token, &input_attrs.auto_copy) {
+            (Some(ref_token), None) => errors.redundant(
+                quote!(#ref_token),
+                "`ref` has no effect because the container is not `auto_copy`",
+                input_attrs,
+            ),
+            (None, Some(auto_copy))
+                if is_copy.is_none()
+                    && clone.is_none()
+                    && option.is_none()
+                    && deref.is_none()
+                    && types::is_copy(&field.ty) =>
+            {
+                is_copy = Some(auto_copy.clone());
+            }
+            _ => {}
+        }
+
+        let clone = match clone {
+            Some(ident) if is_copy.is_some() => {
+                errors.push(Error::new_spanned(ident, "`clone` cannot be combined with `copy`"));
+                None
+            }
+            Some(ident) => Some(ident),
+            None if is_copy.is_none() && option.is_none() && deref.is_none() => {
+                input_attrs.clone.clone()
+            }
+            None => None,
+        };
+
+        // Getters returning by value cannot borrow through `option` or `deref`.
+        let by_value = is_copy.as_ref().or(clone.as_ref());
+        if let Some(by_value) = by_value {
+            if let Some((ident, _)) = option.take() {
+                errors.push(Error::new_spanned(
+                    ident,
+                    format!("`option` cannot be combined with `{}`", by_value),
+                ));
+            }
+            if let Some((ident, _)) = deref.take() {
+                errors.push(Error::new_spanned(
+                    ident,
+                    format!("`deref` cannot be combined with `{}`", by_value),
+                ));
+            }
+        }
+
+        let option = match option {
+            Some((ident, accessors)) => match types::option_inner(&field.ty) {
+                Some(inner) => Some(OptionField { ident, inner, accessors }),
+                None => {
+                    errors.push(Error::new_spanned(
+                        ident,
+                        "`option` requires a field of type `Option<T>`",
+                    ));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        // Option fields deref the wrapped value instead.
+        let deref_subject = match &option {
+            Some(option) => option.inner,
+            None => &field.ty,
+        };
+
+        let deref = match deref {
+            Some((_, DerefTarget::Explicit(target))) => Some(quote!(#target)),
+            Some((ident, DerefTarget::Infer)) => match types::deref_target(deref_subject, true) {
+                Some(target) => Some(target),
+                None => {
+                    errors.push(Error::new_spanned(
+                        ident,
+                        "Cannot infer the deref target of this type, use `deref = Target` instead",
+                    ));
+                    None
+                }
+            },
+            Some((_, DerefTarget::Disabled)) => None,
+            None if input_attrs.deref && by_value.is_none() => {
+                types::deref_target(deref_subject, false)
+            }
+            None => None,
+        };
+
+        // Clones and deref coercions call trait methods, which are not allowed in `const fn`.
+        let constness = match const_token {
+            Some(token) => {
+                if let Some(clone) = &clone {
+                    errors.push(Error::new_spanned(
+                        token,
+                        format!("`const` cannot be combined with `{}`", clone),
+                    ));
+                    false
+                } else if deref.is_some() {
+                    errors
+                        .push(Error::new_spanned(token, "`const` cannot be combined with `deref`"));
+                    false
+                } else {
+                    true
+                }
             }
-        } else if attr.path.is_ident("doc") {
-            docs.push(attr);
+            None => input_attrs.constness && clone.is_none() && deref.is_none(),
+        };
+
+        // `option(into)` and `option(take)` are equivalent to `into` and `take`.
+        if let Some(option) = &option {
+            into |= option.accessors.into;
+            take |= option.accessors.take;
         }
+
+        // Accessor-specific visibility falls back to the container-level setting,
+        // then to the visibility of the field.
+        let resolve_vis = |vis: Option<syn::Visibility>, input_vis: &Option<syn::Visibility>| {
+            vis.or_else(|| input_vis.clone()).unwrap_or_else(|| field_vis.clone())
+        };
+        let get_vis = resolve_vis(get_vis, &input_attrs.get_vis);
+        let mut_vis = resolve_vis(mut_vis, &input_attrs.mut_vis);
+        let set_vis = resolve_vis(set_vis, &input_attrs.set_vis);
+        let with_vis = resolve_vis(with_vis, &input_attrs.with_vis);
+
+        Some(Self {
+            field,
+            index,
+            name,
+            getter,
+            mut_getter,
+            setter,
+            naming: input_attrs.naming.clone(),
+            get_vis,
+            mut_vis,
+            set_vis,
+            is_copy,
+            clone,
+            deref,
+            option,
+            constness,
+            inline,
+            must_use,
+            track_caller,
+            accessors,
+            chain,
+            into,
+            with,
+            with_vis,
+            replace,
+            take,
+            update,
+            shared,
+            docs,
+            auto_doc: input_attrs.auto_doc,
+            doc_templates,
+            attrs,
+        })
     }
 
-    if !derive {
-        return Ok(());
+    /// Builds the name of a generated method from the field name.
+    ///
+    /// Tuple fields without an explicit name use the index instead,
+    /// e.g. `_0`, `_0_mut` and `set_0`.
+    /// The `r#` prefix of raw identifiers is dropped when affixes are added,
+    /// e.g. `r#type` becomes `set_type`,
+    /// while affixed names that collide with a keyword become raw identifiers.
+    /// Names that cannot be raw identifiers, such as `super`, are reported to `errors`.
+    fn method_ident(&self, prefix: &str, suffix: &str, errors: &mut Errors) -> syn::Ident {
+        let name = match &self.name {
+            Some(name) if prefix.is_empty() && suffix.is_empty() => return name.clone(),
+            Some(name) => format!("{}{}{}", prefix, name.unraw(), suffix),
+            None if prefix.is_empty() => format!("_{}{}", self.index, suffix),
+            None => format!("{}{}{}", prefix, self.index, suffix),
+        };
+        // Keep the span of the name so that errors in the generated methods point at the field.
+        let span = match &self.name {
+            Some(name) => name.span(),
+            None => self.field.span(),
+        };
+
+        if syn::parse_str::<syn::Ident>(&name).is_ok() {
+            syn::Ident::new(&name, span)
+        } else if ["self", "Self", "super", "crate"].contains(&name.as_str()) {
+            errors.push(Error::new_spanned(
+                self.field,
+                format!(
+                    "The method name `{}` is reserved, use different naming affixes or rename the \
+                     accessor",
+                    name
+                ),
+            ));
+            format_ident!("{}_", name, span = span)
+        } else {
+            syn::Ident::new_raw(&name, span)
+        }
     }
 
-    let ref_op = match is_copy {
-        Some(_) => quote!(),
-        None => quote_spanned!(field.span() => &),
-    };
+    /// Returns the field name used in generated documentation.
+    fn doc_name(&self) -> String {
+        match &self.field.ident {
+            Some(ident) => ident.unraw().to_string(),
+            None => self.index.to_string(),
+        }
+    }
 
-    methods.extend(quote_spanned! { field.span() =>
-        #(#docs)*
-        #[must_use = "Getters have no side effect"]
-        #[inline(always)]
-        #field_vis fn #field_ident(&self) -> #ref_op #field_ty {
-            #ref_op self.#field_ident
+    /// Generates the documentation of a method.
+    ///
+    /// The summary is taken from `template`, or synthesized with `auto_doc`,
+    /// and is followed by the field documentation.
+    fn method_docs(&self, template: Option<&String>, summary: &str) -> TokenStream {
+        let summary = match template {
+            Some(template) => Some(template.replace("{field}", &self.doc_name())),
+            None if self.auto_doc => Some(summary.to_string()),
+            None => None,
+        };
+        summarized_docs(summary.as_deref(), &self.docs)
+    }
+
+    /// Asserts that a `copy` field implements `Copy`,
+    /// so that the user sees this error instead of a move out of `self`.
+    ///
+    /// Types that are syntactically known to be `Copy` need no assertion.
+    fn copy_assertion(&self) -> Option<TokenStream> {
+        let field_ty = &self.field.ty;
+        if self.is_copy.is_none() || types::is_copy(field_ty) {
+            return None;
         }
-    });
 
-    if mutable {
-        let setter = format_ident!("set_{}", &field_ident);
-        let mut_getter = format_ident!("{}_mut", &field_ident);
+        let message = format!(
+            "field `{}` is marked `copy` but `{{Self}}` does not implement `Copy`",
+            self.doc_name()
+        );
+        Some(quote_spanned! { self.field.span() =>
+            #[diagnostic::on_unimplemented(
+                message = #message,
+                label = "this type is not `Copy`",
+                note = "use `#[gusket(clone)]` to return a clone instead",
+            )]
+            trait AssertCopy {}
+            impl<T: ?::core::marker::Sized + ::core::marker::Copy> AssertCopy for T {}
+            const fn assert_copy<T: ?::core::marker::Sized + AssertCopy>() {}
+            assert_copy::<#field_ty>();
+        })
+    }
+
+    fn emit(&self, access: &FieldAccess, methods: &mut TokenStream, errors: &mut Errors) {
+        let Self { field, get_vis, mut_vis, set_vis, attrs, doc_templates, .. } = self;
+        let name = self.doc_name();
+        let field_ty = &field.ty;
+        let inline = self.inline.attr();
+        let track_caller = self.track_caller.then(|| quote!(#[track_caller]));
 
-        methods.extend(quote_spanned! { field.span() =>
-            #(#docs)*
-            #[must_use = "Mutable getters have no side effect"]
-            #[inline(always)]
-            #field_vis fn #mut_getter(&mut self) -> &mut #field_ty {
-                &mut self.#field_ident
+        // Setter parameters are named after the field even if the getter is renamed.
+        let param = self.method_ident("", "", errors);
+        let naming = &self.naming;
+        let getter_prefix = match &naming.bool_prefix {
+            Some(prefix) if types::is_bool(field_ty) => prefix,
+            _ => &naming.getter_prefix,
+        };
+
+        let ref_op = match self.is_copy {
+            Some(_) => quote!(),
+            None => quote_spanned!(field.span() => &),
+        };
+        // The getter body relies on deref coercion to reach the borrowed form.
+        let (getter_ty, getter_body) = match (&self.option, &self.deref) {
+            (Some(_), Some(target)) => (
+                quote_spanned!(field.span() => ::core::option::Option<&#target>),
+                access.wrap(|place| {
+                    quote_spanned!(field.span() => ::core::option::Option::as_deref(&#place))
+                }),
+            ),
+            (Some(option), None) => {
+                let inner = option.inner;
+                (
+                    quote_spanned!(field.span() => ::core::option::Option<&#inner>),
+                    access.wrap(|place| {
+                        quote_spanned!(field.span() => ::core::option::Option::as_ref(&#place))
+                    }),
+                )
             }
+            (None, Some(target)) => (
+                quote_spanned!(field.span() => &#target),
+                access.wrap(|place| quote_spanned!(field.span() => &#place)),
+            ),
+            (None, None) if self.clone.is_some() => (
+                quote_spanned!(field.span() => #field_ty),
+                access.wrap(|place| {
+                    quote_spanned!(field.span() => ::core::clone::Clone::clone(&#place))
+                }),
+            ),
+            (None, None) => (
+                quote_spanned!(field.span() => #ref_op #field_ty),
+                access.wrap(|place| quote_spanned!(field.span() => #ref_op #place)),
+            ),
+        };
 
-            #(#docs)*
-            #[inline(always)]
-            #field_vis fn #setter(&mut self, #field_ident: #field_ty) {
-                self.#field_ident = #field_ident;
+        // Reading a union field is unsafe because the field may not be active.
+        // Assigning to a union field is always safe, so setters and withers stay safe.
+        let (read_unsafety, read_safety_docs) = if let FieldAccess::UnionMember(_) = access {
+            let field_name = field.ident.as_ref().expect("Union fields are named");
+            let read_safety_docs = safety_docs(
+                field.span(),
+                &format!(" `{}` must be the active field of the union.", field_name),
+            );
+            (Some(quote!(unsafe)), Some(read_safety_docs))
+        } else {
+            (None, None)
+        };
+        let unsafe_read = |body: TokenStream| match read_unsafety {
+            Some(_) => quote!(unsafe { #body }),
+            None => body,
+        };
+
+        if self.accessors.get {
+            let copy_assertion = self.copy_assertion();
+            let getter_body = unsafe_read(getter_body);
+            let summary = if self.is_copy.is_some() {
+                format!("Returns the value of the `{}` field.", name)
+            } else if self.clone.is_some() {
+                format!("Returns a clone of the `{}` field.", name)
+            } else if self.option.is_some() {
+                format!("Returns a reference to the value of the `{}` field, if any.", name)
+            } else {
+                format!("Returns a reference to the `{}` field.", name)
+            };
+            let getter = self
+                .getter
+                .clone()
+                .unwrap_or_else(|| self.method_ident(getter_prefix, &naming.getter_suffix, errors));
+            let docs = self.method_docs(doc_templates.get.as_ref(), &summary);
+            let constness = self.constness.then(|| quote!(const));
+            let must_use = self.must_use.attr("Getters have no side effect");
+            methods.extend(quote_spanned! { field.span() =>
+                #docs
+                #(#attrs)*
+                #read_safety_docs
+                #must_use
+                #inline
+                #get_vis #constness #read_unsafety fn #getter(&self) -> #getter_ty {
+                    #copy_assertion
+                    #getter_body
+                }
+            });
+        }
+
+        let setter_ty = if self.into {
+            quote_spanned!(field.span() => impl ::core::convert::Into<#field_ty>)
+        } else {
+            quote!(#field_ty)
+        };
+        let value = if self.into {
+            quote_spanned!(field.span() => ::core::convert::Into::into(#param))
+        } else {
+            quote!(#param)
+        };
+        let assign = |place: TokenStream| quote_spanned!(field.span() => #place = #value;);
+
+        if self.accessors.get_mut {
+            let mut_getter = self.mut_getter.clone().unwrap_or_else(|| {
+                self.method_ident(&naming.mut_prefix, &naming.mut_suffix, errors)
+            });
+            let must_use = self.must_use.attr("Mutable getters have no side effect");
+            let mut_getter_body =
+                unsafe_read(access.wrap(|place| quote_spanned!(field.span() => &mut #place)));
+            let docs = self.method_docs(
+                doc_templates.mut_.as_ref(),
+                &format!("Returns a mutable reference to the `{}` field.", name),
+            );
+
+            methods.extend(quote_spanned! { field.span() =>
+                #docs
+                #(#attrs)*
+                #read_safety_docs
+                #must_use
+                #inline
+                #mut_vis #read_unsafety fn #mut_getter(&mut self) -> &mut #field_ty {
+                    #mut_getter_body
+                }
+            });
+        }
+
+        if self.accessors.set {
+            let setter = self.setter.clone().unwrap_or_else(|| {
+                self.method_ident(&naming.setter_prefix, &naming.setter_suffix, errors)
+            });
+            // Chained setters are not `#[must_use]` so that they can also be called as statements.
+            let (setter_ret, setter_self) = if self.chain {
+                (Some(quote_spanned!(field.span() => -> &mut Self)), Some(quote!(self)))
+            } else {
+                (None, None)
+            };
+            let setter_body = access.wrap(assign);
+            let docs = self
+                .method_docs(doc_templates.set.as_ref(), &format!("Sets the `{}` field.", name));
+
+            methods.extend(quote_spanned! { field.span() =>
+                #docs
+                #(#attrs)*
+                #inline
+                #track_caller
+                #set_vis fn #setter(&mut self, #param: #setter_ty) #setter_ret {
+                    #setter_body
+                    #setter_self
+                }
+            })
+        }
+
+        if self.with {
+            let wither = self.method_ident("with_", "", errors);
+            let with_vis = &self.with_vis;
+            let must_use = self.must_use.attr("Withers return the modified value");
+            let with_body = access.wrap_match(quote!(&mut self), assign);
+            let docs = self.method_docs(
+                doc_templates.doc.as_ref(),
+                &format!("Returns `self` with the `{}` field set to the given value.", name),
+            );
+
+            methods.extend(quote_spanned! { field.span() =>
+                #docs
+                #(#attrs)*
+                #must_use
+                #inline
+                #track_caller
+                #with_vis fn #wither(mut self, #param: #setter_ty) -> Self {
+                    #with_body
+                    self
+                }
+            });
+        }
+
+        if self.accessors.set && self.replace {
+            let replacer = self.method_ident("replace_", "", errors);
+            let docs = self.method_docs(
+                doc_templates.doc.as_ref(),
+                &format!("Replaces the `{}` field, returning the previous value.", name),
+            );
+            let body = unsafe_read(access.wrap(
+                |place| quote_spanned!(field.span() => ::core::mem::replace(&mut #place, #value)),
+            ));
+
+            methods.extend(quote_spanned! { field.span() =>
+                #docs
+                #(#attrs)*
+                #read_safety_docs
+                #inline
+                #track_caller
+                #set_vis #read_unsafety fn #replacer(&mut self, #param: #setter_ty) -> #field_ty {
+                    #body
+                }
+            });
+        }
+
+        if self.accessors.set && self.take {
+            let taker = self.method_ident("take_", "", errors);
+            let docs = self.method_docs(
+                doc_templates.doc.as_ref(),
+                &format!("Takes the `{}` field, leaving the default value in its place.", name),
+            );
+            let body = unsafe_read(access.wrap(|place| match &self.option {
+                Some(_) => {
+                    quote_spanned!(field.span() => ::core::option::Option::take(&mut #place))
+                }
+                None => quote_spanned!(field.span() => ::core::mem::take(&mut #place)),
+            }));
+
+            methods.extend(quote_spanned! { field.span() =>
+                #docs
+                #(#attrs)*
+                #read_safety_docs
+                #inline
+                #track_caller
+                #set_vis #read_unsafety fn #taker(&mut self) -> #field_ty {
+                    #body
+                }
+            });
+        }
+
+        if self.accessors.get_mut && self.update {
+            let updater = self.method_ident("update_", "", errors);
+            let docs = self.method_docs(
+                doc_templates.doc.as_ref(),
+                &format!("Updates the `{}` field in place.", name),
+            );
+            let body =
+                unsafe_read(access.wrap(|place| quote_spanned!(field.span() => f(&mut #place))));
+
+            methods.extend(quote_spanned! { field.span() =>
+                #docs
+                #(#attrs)*
+                #read_safety_docs
+                #inline
+                #track_caller
+                #mut_vis #read_unsafety fn #updater(
+                    &mut self,
+                    f: impl ::core::ops::FnOnce(&mut #field_ty),
+                ) {
+                    #body
+                }
+            });
+        }
+
+        if let Some(option) = &self.option {
+            self.emit_option_accessors(option, access, methods, errors);
+        }
+    }
+
+    fn emit_option_accessors(
+        &self,
+        option: &OptionField,
+        access: &FieldAccess,
+        methods: &mut TokenStream,
+        errors: &mut Errors,
+    ) {
+        let Self { field, get_vis, mut_vis, set_vis, attrs, doc_templates, .. } = self;
+        let name = self.doc_name();
+        let inner = option.inner;
+        let inline = self.inline.attr();
+        let track_caller = self.track_caller.then(|| quote!(#[track_caller]));
+
+        if self.accessors.get && option.accessors.has {
+            let has = self.method_ident("has_", "", errors);
+            let must_use = self.must_use.attr("Getters have no side effect");
+            let docs = self.method_docs(
+                doc_templates.doc.as_ref(),
+                &format!("Returns whether the `{}` field is set.", name),
+            );
+            let body = access.wrap(
+                |place| quote_spanned!(field.span() => ::core::option::Option::is_some(&#place)),
+            );
+            methods.extend(quote_spanned! { field.span() =>
+                #docs
+                #(#attrs)*
+                #must_use
+                #inline
+                #get_vis fn #has(&self) -> bool {
+                    #body
+                }
+            });
+        }
+
+        if self.accessors.set && option.accessors.clear {
+            let clear = self.method_ident("clear_", "", errors);
+            let docs = self
+                .method_docs(doc_templates.doc.as_ref(), &format!("Clears the `{}` field.", name));
+            let body = access.wrap(
+                |place| quote_spanned!(field.span() => #place = ::core::option::Option::None;),
+            );
+            methods.extend(quote_spanned! { field.span() =>
+                #docs
+                #(#attrs)*
+                #inline
+                #track_caller
+                #set_vis fn #clear(&mut self) {
+                    #body
+                }
+            });
+        }
+
+        if self.accessors.get_mut && option.accessors.get_or_insert_with {
+            let get_or_insert_with = self.method_ident("", "_or_insert_with", errors);
+            let docs = self.method_docs(
+                doc_templates.doc.as_ref(),
+                &format!(
+                    "Returns a mutable reference to the `{}` field, inserting the result of `f` \
+                     if it is unset.",
+                    name
+                ),
+            );
+            let body = access.wrap(|place| {
+                quote_spanned! { field.span() =>
+                    ::core::option::Option::get_or_insert_with(&mut #place, f)
+                }
+            });
+            methods.extend(quote_spanned! { field.span() =>
+                #docs
+                #(#attrs)*
+                #inline
+                #track_caller
+                #mut_vis fn #get_or_insert_with(
+                    &mut self,
+                    f: impl ::core::ops::FnOnce() -> #inner,
+                ) -> &mut #inner {
+                    #body
+                }
+            });
+        }
+    }
+}
+
+/// Checks whether a field or variant attribute is forwarded to the generated methods.
+///
+/// `cfg` and `cfg_attr` are always forwarded as a defensive measure:
+/// rustc already strips them from the derive input before the macro runs,
+/// so they only matter for input that has not been cfg-expanded.
+/// `deprecated`, `allow` and `warn` are only forwarded with `forward_attrs`.
+fn is_forwarded(attr: &syn::Attribute, forward_attrs: bool) -> bool {
+    let path = &attr.path;
+    path.is_ident("cfg")
+        || path.is_ident("cfg_attr")
+        || (forward_attrs
+            && (path.is_ident("deprecated") || path.is_ident("allow") || path.is_ident("warn")))
+}
+
+/// Generates method documentation from an optional summary followed by `docs` as a separate paragraph.
+fn summarized_docs(summary: Option<&str>, docs: &[&syn::Attribute]) -> TokenStream {
+    let summary = match summary {
+        Some(summary) => format!(" {}", summary),
+        None => return quote!(#(#docs)*),
+    };
+
+    if docs.is_empty() {
+        quote!(#[doc = #summary])
+    } else {
+        quote!(#[doc = #summary] #[doc = ""] #(#docs)*)
+    }
+}
+
+/// Generates a `# Safety` documentation section.
+///
+/// The section must have the span of the field like the method itself,
+/// since clippy ignores documentation from macro expansions when checking `missing_safety_doc`.
+fn safety_docs(span: Span, requirement: &str) -> TokenStream {
+    quote_spanned! { span =>
+        #[doc = ""]
+        #[doc = " # Safety"]
+        #[doc = #requirement]
+    }
+}
+
+/// How generated methods reach the field from `self`.
+enum FieldAccess {
+    /// A struct field accessed directly.
+    Member(syn::Member),
+    /// A union field accessed directly.
+    UnionMember(syn::Member),
+    /// A field present in every listed variant pattern, bound by reference as `binding`.
+    Variants { patterns: Vec<TokenStream>, binding: syn::Ident },
+}
+
+impl FieldAccess {
+    /// Builds a method body from `body_fn`,
+    /// which receives a place expression for the field.
+    fn wrap(&self, body_fn: impl FnOnce(TokenStream) -> TokenStream) -> TokenStream {
+        self.wrap_match(quote!(self), body_fn)
+    }
+
+    /// Like [`wrap`](Self::wrap), but matches variants on `scrutinee` instead of `self`.
+    fn wrap_match(
+        &self,
+        scrutinee: TokenStream,
+        body_fn: impl FnOnce(TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        match self {
+            Self::Member(member) | Self::UnionMember(member) => body_fn(quote!(self.#member)),
+            Self::Variants { patterns, binding } => {
+                let body = body_fn(quote!(*#binding));
+                quote! {
+                    match #scrutinee {
+                        #(#patterns)|* => { #body }
+                    }
+                }
             }
-        })
+        }
+    }
+}
+
+/// The basic accessors generated for a field.
+#[derive(Clone, Copy)]
+struct Accessors {
+    get:     bool,
+    get_mut: bool,
+    set:     bool,
+}
+
+impl Accessors {
+    fn set_mutable(&mut self, mutable: bool) {
+        self.get_mut = mutable;
+        self.set = mutable;
     }
 
-    Ok(())
+    /// Clears the inherited accessors on the first `get`, `get_mut` or `set` flag,
+    /// so that only the explicitly selected accessors are generated.
+    fn select(&mut self, selecting: &mut bool) -> &mut Self {
+        if !*selecting {
+            *selecting = true;
+            *self = Accessors { get: false, get_mut: false, set: false };
+        }
+        self
+    }
+}
+
+/// The `#[inline]` policy of generated methods.
+#[derive(Clone, Copy)]
+enum Inline {
+    Always,
+    Hint,
+    Never,
+}
+
+impl Inline {
+    fn attr(self) -> TokenStream {
+        match self {
+            Inline::Always => quote!(#[inline(always)]),
+            Inline::Hint => quote!(#[inline]),
+            Inline::Never => quote!(#[inline(never)]),
+        }
+    }
+}
+
+impl Parse for Inline {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        if ident == "always" {
+            Ok(Inline::Always)
+        } else if ident == "hint" {
+            Ok(Inline::Hint)
+        } else if ident == "never" {
+            Ok(Inline::Never)
+        } else {
+            Err(Error::new_spanned(ident, "Expected `always`, `hint` or `never`"))
+        }
+    }
+}
+
+/// The `#[must_use]` policy of generated methods without side effects.
+#[derive(Clone)]
+enum MustUse {
+    /// Uses the default message of each method.
+    Default,
+    Disabled,
+    Message(syn::LitStr),
+}
+
+impl MustUse {
+    fn attr(&self, default_message: &str) -> TokenStream {
+        match self {
+            MustUse::Default => quote!(#[must_use = #default_message]),
+            MustUse::Disabled => quote!(),
+            MustUse::Message(message) => quote!(#[must_use = #message]),
+        }
+    }
+}
+
+impl Parse for MustUse {
+    fn parse(input: ParseStream) -> Result<Self> {
+        match input.parse()? {
+            syn::Lit::Bool(lit) if lit.value => Ok(MustUse::Default),
+            syn::Lit::Bool(_) => Ok(MustUse::Disabled),
+            syn::Lit::Str(message) => Ok(MustUse::Message(message)),
+            lit => Err(Error::new_spanned(lit, "Expected a boolean or a message string")),
+        }
+    }
+}
+
+/// Documentation templates of generated methods, where `{field}` is replaced by the field name.
+#[derive(Clone, Default)]
+struct DocTemplates {
+    /// Applies to all accessors without a more specific template.
+    doc:  Option<String>,
+    get:  Option<String>,
+    mut_: Option<String>,
+    set:  Option<String>,
+}
+
+impl DocTemplates {
+    fn set(&mut self, ident: &syn::Ident, template: String) {
+        let slot = match ident.to_string().as_str() {
+            "doc" => &mut self.doc,
+            "get_doc" => &mut self.get,
+            "mut_doc" => &mut self.mut_,
+            "set_doc" => &mut self.set,
+            _ => unreachable!("checked in attribute parsing"),
+        };
+        *slot = Some(template);
+    }
+
+    /// Resolves the templates of a field,
+    /// preferring field-level templates over container-level ones.
+    fn resolve(self, input: &Self) -> Self {
+        let doc = self.doc.clone();
+        let pick = |field: Option<String>, input_specific: &Option<String>| {
+            field
+                .or_else(|| doc.clone())
+                .or_else(|| input_specific.clone())
+                .or_else(|| input.doc.clone())
+        };
+        Self {
+            get:  pick(self.get, &input.get),
+            mut_: pick(self.mut_, &input.mut_),
+            set:  pick(self.set, &input.set),
+            doc:  self.doc.or_else(|| input.doc.clone()),
+        }
+    }
+}
+
+/// The container and field attributes that set documentation templates.
+const DOC_TEMPLATES: &[&str] = &["doc", "get_doc", "mut_doc", "set_doc"];
+
+/// The prefixes and suffixes of generated accessor names.
+#[derive(Clone)]
+struct Naming {
+    getter_prefix: String,
+    getter_suffix: String,
+    mut_prefix:    String,
+    mut_suffix:    String,
+    setter_prefix: String,
+    setter_suffix: String,
+    /// Replaces `getter_prefix` for `bool` fields.
+    bool_prefix:   Option<String>,
+}
+
+impl Default for Naming {
+    fn default() -> Self {
+        Self {
+            getter_prefix: String::new(),
+            getter_suffix: String::new(),
+            mut_prefix:    String::new(),
+            mut_suffix:    String::from("_mut"),
+            setter_prefix: String::from("set_"),
+            setter_suffix: String::new(),
+            bool_prefix:   None,
+        }
+    }
 }
 
 struct InputAttrs {
-    vis:     syn::Visibility,
-    mutable: bool,
-    derive:  bool,
+    vis:            syn::Visibility,
+    accessors:      Accessors,
+    selecting:      bool,
+    tracker:        AttrTracker,
+    derive:         bool,
+    chain:          bool,
+    into:           bool,
+    with:           bool,
+    get_vis:        Option<syn::Visibility>,
+    mut_vis:        Option<syn::Visibility>,
+    set_vis:        Option<syn::Visibility>,
+    with_vis:       Option<syn::Visibility>,
+    replace:        bool,
+    take:           bool,
+    update:         bool,
+    clone:          Option<syn::Ident>,
+    deref:          bool,
+    newtype:        Option<syn::Ident>,
+    constness:      bool,
+    inline:         Inline,
+    must_use:       MustUse,
+    track_caller:   bool,
+    forward_attrs:  bool,
+    auto_doc:       bool,
+    doc_templates:  DocTemplates,
+    naming:         Naming,
+    deny_redundant: bool,
+    auto_copy:      Option<syn::Ident>,
 }
 
 impl InputAttrs {
     fn new(vis: &syn::Visibility) -> Self {
-        InputAttrs { vis: vis.clone(), mutable: true, derive: false }
+        InputAttrs {
+            vis:            vis.clone(),
+            accessors:      Accessors { get: true, get_mut: true, set: true },
+            selecting:      false,
+            tracker:        AttrTracker::default(),
+            derive:         false,
+            chain:          false,
+            into:           false,
+            with:           false,
+            get_vis:        None,
+            mut_vis:        None,
+            set_vis:        None,
+            with_vis:       None,
+            replace:        false,
+            take:           false,
+            update:         false,
+            clone:          None,
+            deref:          false,
+            newtype:        None,
+            constness:      false,
+            inline:         Inline::Always,
+            must_use:       MustUse::Default,
+            track_caller:   false,
+            forward_attrs:  false,
+            auto_doc:       false,
+            doc_templates:  DocTemplates::default(),
+            naming:         Naming::default(),
+            deny_redundant: false,
+            auto_copy:      None,
+        }
     }
 
+    /// Applies the valid items of a container attribute and reports the invalid ones.
     fn apply(&mut self, attr: &syn::Attribute) -> Result<()> {
-        let attr_list: Punctuated<InputAttr, syn::Token![,]> =
-            attr.parse_args_with(Punctuated::parse_terminated)?;
+        let mut errors = Errors::default();
+
+        for attr in parse_attr_list::<InputAttr>(attr, &mut errors) {
+            if errors.ok(self.tracker.track(attr.key())).is_none() {
+                continue;
+            }
 
-        for attr in attr_list {
             match attr {
                 InputAttr::Vis(_, vis) => self.vis = vis,
-                InputAttr::Immut(_) => self.mutable = false,
+                InputAttr::Immut(_) => self.accessors.set_mutable(false),
+                InputAttr::Get(_) => self.accessors.select(&mut self.selecting).get = true,
+                InputAttr::GetMut(_) => self.accessors.select(&mut self.selecting).get_mut = true,
+                InputAttr::Set(_) => self.accessors.select(&mut self.selecting).set = true,
+                InputAttr::With(_) => self.with = true,
+                InputAttr::GetVis(_, vis) => self.get_vis = Some(vis),
+                InputAttr::MutVis(_, vis) => self.mut_vis = Some(vis),
+                InputAttr::SetVis(_, vis) => self.set_vis = Some(vis),
+                InputAttr::WithVis(_, vis) => self.with_vis = Some(vis),
                 InputAttr::All(_) => self.derive = true,
+                InputAttr::Chain(_) => self.chain = true,
+                InputAttr::Into(_) => self.into = true,
+                InputAttr::Replace(_) => self.replace = true,
+                InputAttr::Take(_) => self.take = true,
+                InputAttr::Update(_) => self.update = true,
+                InputAttr::Clone(ident) => self.clone = Some(ident),
+                InputAttr::Deref(_) => self.deref = true,
+                InputAttr::Newtype(ident) => self.newtype = Some(ident),
+                InputAttr::Const(_) => self.constness = true,
+                InputAttr::Inline(_, policy) => self.inline = policy,
+                InputAttr::MustUse(_, policy) => self.must_use = policy,
+                InputAttr::TrackCaller(_) => self.track_caller = true,
+                InputAttr::ForwardAttrs(_) => self.forward_attrs = true,
+                InputAttr::AutoDoc(_) => self.auto_doc = true,
+                InputAttr::DenyRedundant(_) => self.deny_redundant = true,
+                InputAttr::AutoCopy(ident) => self.auto_copy = Some(ident),
+                InputAttr::DocTemplate(ident, template) => {
+                    self.doc_templates.set(&ident, template.value())
+                }
+                InputAttr::Affix(ident, affix) => {
+                    let affix = affix.value();
+                    let naming = &mut self.naming;
+                    match ident.to_string().as_str() {
+                        "getter_prefix" => naming.getter_prefix = affix,
+                        "getter_suffix" => naming.getter_suffix = affix,
+                        "mut_prefix" => naming.mut_prefix = affix,
+                        "mut_suffix" => naming.mut_suffix = affix,
+                        "setter_prefix" => naming.setter_prefix = affix,
+                        "setter_suffix" => naming.setter_suffix = affix,
+                        "bool_prefix" => naming.bool_prefix = Some(affix),
+                        _ => unreachable!("checked in InputAttr::parse"),
+                    }
+                }
             }
         }
 
-        Ok(())
+        errors.finish()
     }
 }
 
+/// The container attributes that customize accessor names.
+const AFFIXES: &[&str] = &[
+    "getter_prefix",
+    "getter_suffix",
+    "mut_prefix",
+    "mut_suffix",
+    "setter_prefix",
+    "setter_suffix",
+    "bool_prefix",
+];
+
 enum InputAttr {
     Vis(syn::Ident, syn::Visibility),
     Immut(syn::Ident),
+    Get(syn::Ident),
+    GetMut(syn::Ident),
+    Set(syn::Ident),
+    With(syn::Ident),
+    GetVis(syn::Ident, syn::Visibility),
+    MutVis(syn::Ident, syn::Visibility),
+    SetVis(syn::Ident, syn::Visibility),
+    WithVis(syn::Ident, syn::Visibility),
     All(syn::Ident),
+    Chain(syn::Ident),
+    Into(syn::Ident),
+    Replace(syn::Ident),
+    Take(syn::Ident),
+    Update(syn::Ident),
+    Clone(syn::Ident),
+    Deref(syn::Ident),
+    Newtype(syn::Ident),
+    Const(syn::Token![const]),
+    Inline(syn::Ident, Inline),
+    MustUse(syn::Ident, MustUse),
+    TrackCaller(syn::Ident),
+    ForwardAttrs(syn::Ident),
+    AutoDoc(syn::Ident),
+    DenyRedundant(syn::Ident),
+    AutoCopy(syn::Ident),
+    DocTemplate(syn::Ident, syn::LitStr),
+    Affix(syn::Ident, syn::LitStr),
+}
+
+impl InputAttr {
+    /// Returns the leading tokens identifying the attribute.
+    fn key(&self) -> TokenStream {
+        match self {
+            InputAttr::Const(token) => quote!(#token),
+            InputAttr::Vis(ident, _)
+            | InputAttr::GetVis(ident, _)
+            | InputAttr::MutVis(ident, _)
+            | InputAttr::SetVis(ident, _)
+            | InputAttr::WithVis(ident, _)
+            | InputAttr::Inline(ident, _)
+            | InputAttr::MustUse(ident, _)
+            | InputAttr::DocTemplate(ident, _)
+            | InputAttr::Affix(ident, _)
+            | InputAttr::Immut(ident)
+            | InputAttr::Get(ident)
+            | InputAttr::GetMut(ident)
+            | InputAttr::Set(ident)
+            | InputAttr::With(ident)
+            | InputAttr::All(ident)
+            | InputAttr::Chain(ident)
+            | InputAttr::Into(ident)
+            | InputAttr::Replace(ident)
+            | InputAttr::Take(ident)
+            | InputAttr::Update(ident)
+            | InputAttr::Clone(ident)
+            | InputAttr::Deref(ident)
+            | InputAttr::Newtype(ident)
+            | InputAttr::TrackCaller(ident)
+            | InputAttr::ForwardAttrs(ident)
+            | InputAttr::AutoDoc(ident)
+            | InputAttr::DenyRedundant(ident)
+            | InputAttr::AutoCopy(ident) => quote!(#ident),
+        }
+    }
 }
 
 impl Parse for InputAttr {
     fn parse(input: ParseStream) -> Result<Self> {
-        let ident: syn::Ident = input.parse()?;
+        if input.peek(syn::Token![const]) {
+            let const_token: syn::Token![const] = input.parse()?;
+            return Ok(Self::Const(const_token));
+        }
+
+        // Keywords such as `mut` and `ref` are parsed as identifiers to report their position.
+        let ident = input.call(syn::Ident::parse_any)?;
         if ident == "vis" {
             input.parse::<syn::Token![=]>()?;
             let vis: syn::Visibility = input.parse()?;
             Ok(Self::Vis(ident, vis))
         } else if ident == "immut" {
             Ok(Self::Immut(ident))
+        } else if ident == "get" {
+            Ok(Self::Get(ident))
+        } else if ident == "get_mut" {
+            Ok(Self::GetMut(ident))
+        } else if ident == "set" {
+            Ok(Self::Set(ident))
+        } else if ident == "with" {
+            Ok(Self::With(ident))
+        } else if ident == "get_vis" {
+            input.parse::<syn::Token![=]>()?;
+            let vis: syn::Visibility = input.parse()?;
+            Ok(Self::GetVis(ident, vis))
+        } else if ident == "mut_vis" {
+            input.parse::<syn::Token![=]>()?;
+            let vis: syn::Visibility = input.parse()?;
+            Ok(Self::MutVis(ident, vis))
+        } else if ident == "set_vis" {
+            input.parse::<syn::Token![=]>()?;
+            let vis: syn::Visibility = input.parse()?;
+            Ok(Self::SetVis(ident, vis))
+        } else if ident == "with_vis" {
+            input.parse::<syn::Token![=]>()?;
+            let vis: syn::Visibility = input.parse()?;
+            Ok(Self::WithVis(ident, vis))
         } else if ident == "all" {
             Ok(Self::All(ident))
+        } else if ident == "chain" {
+            Ok(Self::Chain(ident))
+        } else if ident == "into" {
+            Ok(Self::Into(ident))
+        } else if ident == "replace" {
+            Ok(Self::Replace(ident))
+        } else if ident == "take" {
+            Ok(Self::Take(ident))
+        } else if ident == "update" {
+            Ok(Self::Update(ident))
+        } else if ident == "clone" {
+            Ok(Self::Clone(ident))
+        } else if ident == "deref" {
+            Ok(Self::Deref(ident))
+        } else if ident == "newtype" {
+            Ok(Self::Newtype(ident))
+        } else if ident == "inline" {
+            input.parse::<syn::Token![=]>()?;
+            Ok(Self::Inline(ident, input.parse()?))
+        } else if ident == "must_use" {
+            input.parse::<syn::Token![=]>()?;
+            Ok(Self::MustUse(ident, input.parse()?))
+        } else if ident == "track_caller" {
+            Ok(Self::TrackCaller(ident))
+        } else if ident == "forward_attrs" {
+            Ok(Self::ForwardAttrs(ident))
+        } else if ident == "auto_doc" {
+            Ok(Self::AutoDoc(ident))
+        } else if ident == "deny_redundant" {
+            Ok(Self::DenyRedundant(ident))
+        } else if ident == "auto_copy" {
+            Ok(Self::AutoCopy(ident))
+        } else if DOC_TEMPLATES.iter().any(|template| ident == template) {
+            input.parse::<syn::Token![=]>()?;
+            Ok(Self::DocTemplate(ident, input.parse()?))
+        } else if AFFIXES.iter().any(|affix| ident == affix) {
+            input.parse::<syn::Token![=]>()?;
+            let affix: syn::LitStr = input.parse()?;
+            let value = affix.value();
+            if !value.chars().all(|c| c == '_' || c.is_alphanumeric())
+                || (ident.to_string().ends_with("_prefix")
+                    && value.starts_with(|c: char| c.is_numeric()))
+            {
+                return Err(Error::new_spanned(
+                    affix,
+                    "Naming prefixes and suffixes must form valid identifiers",
+                ));
+            }
+            Ok(Self::Affix(ident, affix))
         } else {
-            Err(Error::new_spanned(ident, "Unsupported attribute"))
+            Err(diagnostics::unsupported(&ident, Position::Container))
         }
     }
 }
@@ -215,8 +1685,79 @@ enum FieldAttr {
     Vis(syn::Ident, syn::Visibility),
     Immut(syn::Ident),
     Mut(syn::Token![mut]),
+    Const(syn::Token![const]),
+    Inline(syn::Ident, Inline),
+    MustUse(syn::Ident, MustUse),
+    TrackCaller(syn::Ident),
+    Get(syn::Ident),
+    GetMut(syn::Ident),
+    Set(syn::Ident),
+    Chain(syn::Ident),
+    Into(syn::Ident),
+    Replace(syn::Ident),
+    Take(syn::Ident),
+    Update(syn::Ident),
+    With(syn::Ident),
+    GetVis(syn::Ident, syn::Visibility),
+    MutVis(syn::Ident, syn::Visibility),
+    SetVis(syn::Ident, syn::Visibility),
+    WithVis(syn::Ident, syn::Visibility),
     Copy(syn::Ident),
+    Ref(syn::Token![ref]),
+    Clone(syn::Ident),
+    Deref(syn::Ident, DerefTarget),
+    Option(syn::Ident, OptionAccessors),
+    ForwardAttrs(syn::Ident),
+    DocTemplate(syn::Ident, syn::LitStr),
     Skip(syn::Ident),
+    Name(syn::Ident, syn::Ident),
+    Rename(syn::Ident, syn::Ident),
+    Getter(syn::Ident, syn::Ident),
+    MutGetter(syn::Ident, syn::Ident),
+    Setter(syn::Ident, syn::Ident),
+    Shared(syn::Ident),
+}
+
+impl FieldAttr {
+    /// Returns the leading tokens identifying the attribute.
+    fn key(&self) -> TokenStream {
+        match self {
+            FieldAttr::Mut(token) => quote!(#token),
+            FieldAttr::Const(token) => quote!(#token),
+            FieldAttr::Ref(token) => quote!(#token),
+            FieldAttr::Vis(ident, _)
+            | FieldAttr::GetVis(ident, _)
+            | FieldAttr::MutVis(ident, _)
+            | FieldAttr::SetVis(ident, _)
+            | FieldAttr::WithVis(ident, _)
+            | FieldAttr::Inline(ident, _)
+            | FieldAttr::MustUse(ident, _)
+            | FieldAttr::Deref(ident, _)
+            | FieldAttr::Option(ident, _)
+            | FieldAttr::DocTemplate(ident, _)
+            | FieldAttr::Name(ident, _)
+            | FieldAttr::Rename(ident, _)
+            | FieldAttr::Getter(ident, _)
+            | FieldAttr::MutGetter(ident, _)
+            | FieldAttr::Setter(ident, _)
+            | FieldAttr::Immut(ident)
+            | FieldAttr::TrackCaller(ident)
+            | FieldAttr::Get(ident)
+            | FieldAttr::GetMut(ident)
+            | FieldAttr::Set(ident)
+            | FieldAttr::Chain(ident)
+            | FieldAttr::Into(ident)
+            | FieldAttr::Replace(ident)
+            | FieldAttr::Take(ident)
+            | FieldAttr::Update(ident)
+            | FieldAttr::With(ident)
+            | FieldAttr::Copy(ident)
+            | FieldAttr::Clone(ident)
+            | FieldAttr::ForwardAttrs(ident)
+            | FieldAttr::Skip(ident)
+            | FieldAttr::Shared(ident) => quote!(#ident),
+        }
+    }
 }
 
 impl Parse for FieldAttr {
@@ -225,20 +1766,162 @@ impl Parse for FieldAttr {
             let mut_token: syn::Token![mut] = input.parse()?;
             return Ok(Self::Mut(mut_token));
         }
+        if input.peek(syn::Token![const]) {
+            let const_token: syn::Token![const] = input.parse()?;
+            return Ok(Self::Const(const_token));
+        }
+        if input.peek(syn::Token![ref]) {
+            let ref_token: syn::Token![ref] = input.parse()?;
+            return Ok(Self::Ref(ref_token));
+        }
 
-        let ident: syn::Ident = input.parse()?;
+        // Other keywords are parsed as identifiers to report them as unsupported.
+        let ident = input.call(syn::Ident::parse_any)?;
         if ident == "vis" {
             input.parse::<syn::Token![=]>()?;
             let vis: syn::Visibility = input.parse()?;
             Ok(Self::Vis(ident, vis))
         } else if ident == "immut" {
             Ok(Self::Immut(ident))
+        } else if ident == "get" {
+            Ok(Self::Get(ident))
+        } else if ident == "get_mut" {
+            Ok(Self::GetMut(ident))
+        } else if ident == "set" {
+            Ok(Self::Set(ident))
+        } else if ident == "chain" {
+            Ok(Self::Chain(ident))
+        } else if ident == "into" {
+            Ok(Self::Into(ident))
+        } else if ident == "replace" {
+            Ok(Self::Replace(ident))
+        } else if ident == "take" {
+            Ok(Self::Take(ident))
+        } else if ident == "update" {
+            Ok(Self::Update(ident))
+        } else if ident == "with" {
+            Ok(Self::With(ident))
+        } else if ident == "get_vis" {
+            input.parse::<syn::Token![=]>()?;
+            let vis: syn::Visibility = input.parse()?;
+            Ok(Self::GetVis(ident, vis))
+        } else if ident == "mut_vis" {
+            input.parse::<syn::Token![=]>()?;
+            let vis: syn::Visibility = input.parse()?;
+            Ok(Self::MutVis(ident, vis))
+        } else if ident == "set_vis" {
+            input.parse::<syn::Token![=]>()?;
+            let vis: syn::Visibility = input.parse()?;
+            Ok(Self::SetVis(ident, vis))
+        } else if ident == "with_vis" {
+            input.parse::<syn::Token![=]>()?;
+            let vis: syn::Visibility = input.parse()?;
+            Ok(Self::WithVis(ident, vis))
         } else if ident == "copy" {
             Ok(Self::Copy(ident))
+        } else if ident == "clone" {
+            Ok(Self::Clone(ident))
+        } else if ident == "deref" {
+            let target = if input.peek(syn::Token![=]) {
+                input.parse::<syn::Token![=]>()?;
+                if input.peek(syn::LitBool) {
+                    let lit: syn::LitBool = input.parse()?;
+                    if lit.value {
+                        DerefTarget::Infer
+                    } else {
+                        DerefTarget::Disabled
+                    }
+                } else {
+                    DerefTarget::Explicit(Box::new(input.parse()?))
+                }
+            } else {
+                DerefTarget::Infer
+            };
+            Ok(Self::Deref(ident, target))
+        } else if ident == "option" {
+            let mut accessors = OptionAccessors::default();
+            if input.peek(syn::token::Paren) {
+                let inner;
+                syn::parenthesized!(inner in input);
+                let flags = Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated_with(
+                    &inner,
+                    syn::Ident::parse_any,
+                )?;
+                for flag in flags {
+                    if flag == "take" {
+                        accessors.take = true;
+                    } else if flag == "clear" {
+                        accessors.clear = true;
+                    } else if flag == "has" {
+                        accessors.has = true;
+                    } else if flag == "get_or_insert_with" {
+                        accessors.get_or_insert_with = true;
+                    } else if flag == "into" {
+                        accessors.into = true;
+                    } else {
+                        return Err(diagnostics::unsupported_option_accessor(&flag));
+                    }
+                }
+            }
+            Ok(Self::Option(ident, accessors))
+        } else if ident == "inline" {
+            input.parse::<syn::Token![=]>()?;
+            Ok(Self::Inline(ident, input.parse()?))
+        } else if ident == "must_use" {
+            input.parse::<syn::Token![=]>()?;
+            Ok(Self::MustUse(ident, input.parse()?))
+        } else if ident == "track_caller" {
+            Ok(Self::TrackCaller(ident))
+        } else if ident == "forward_attrs" {
+            Ok(Self::ForwardAttrs(ident))
+        } else if DOC_TEMPLATES.iter().any(|template| ident == template) {
+            input.parse::<syn::Token![=]>()?;
+            Ok(Self::DocTemplate(ident, input.parse()?))
         } else if ident == "skip" {
             Ok(Self::Skip(ident))
+        } else if ident == "name" {
+            input.parse::<syn::Token![=]>()?;
+            let name: syn::Ident = input.parse()?;
+            Ok(Self::Name(ident, name))
+        } else if ident == "rename" {
+            input.parse::<syn::Token![=]>()?;
+            let name: syn::Ident = input.parse()?;
+            Ok(Self::Rename(ident, name))
+        } else if ident == "getter" {
+            input.parse::<syn::Token![=]>()?;
+            let name: syn::Ident = input.parse()?;
+            Ok(Self::Getter(ident, name))
+        } else if ident == "mut_getter" {
+            input.parse::<syn::Token![=]>()?;
+            let name: syn::Ident = input.parse()?;
+            Ok(Self::MutGetter(ident, name))
+        } else if ident == "setter" {
+            input.parse::<syn::Token![=]>()?;
+            let name: syn::Ident = input.parse()?;
+            Ok(Self::Setter(ident, name))
+        } else if ident == "shared" {
+            Ok(Self::Shared(ident))
         } else {
-            Err(Error::new_spanned(ident, "Unsupported attribute"))
+            Err(diagnostics::unsupported(&ident, Position::Field))
         }
     }
 }
+
+enum DerefTarget {
+    /// `deref` or `deref = true`
+    Infer,
+    /// `deref = Target`
+    Explicit(Box<syn::Type>),
+    /// `deref = false`
+    Disabled,
+}
+
+/// Additional accessors requested in `#[gusket(option(...))]`.
+#[derive(Default)]
+struct OptionAccessors {
+    take:               bool,
+    clear:              bool,
+    has:                bool,
+    get_or_insert_with: bool,
+    into:               bool,
+}
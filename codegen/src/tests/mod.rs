@@ -2,9 +2,9 @@
 
 use proc_macro2::{TokenStream, TokenTree};
 use quote::quote;
-use syn::parse::{Parse, ParseStream};
+use syn::parse::{Parse, ParseStream, Parser};
 
-use crate::{process_field, InputAttrs};
+use crate::{enums, process_field, Errors, FieldAttr, InputAttrs};
 
 fn token_stream_equals(ts1: TokenStream, ts2: TokenStream) -> bool {
     let mut ts1 = ts1.into_iter().fuse();
@@ -56,12 +56,7 @@ fn token_stream_equals(ts1: TokenStream, ts2: TokenStream) -> bool {
     }
 }
 
-fn test_process_field(
-    vis: TokenStream,
-    input_attrs_ts: TokenStream,
-    field: TokenStream,
-    expect: TokenStream,
-) {
+fn parse_input_attrs(vis: TokenStream, input_attrs_ts: TokenStream) -> InputAttrs {
     let mut input_attrs =
         InputAttrs::new(&syn::parse2(vis).expect("Invalid test input (visibility)"));
 
@@ -80,23 +75,156 @@ fn test_process_field(
         input_attrs.apply(&attr).expect("Invalid test input (container attributes)");
     }
 
-    struct NamedFieldParse(syn::Field);
-    impl Parse for NamedFieldParse {
-        fn parse(input: ParseStream) -> syn::Result<Self> {
-            Ok(Self(input.call(syn::Field::parse_named)?))
-        }
+    input_attrs
+}
+
+/// Parses a named field, or a tuple field if the tokens have no name.
+fn parse_field(field: TokenStream) -> syn::Field {
+    syn::Field::parse_named
+        .parse2(field.clone())
+        .or_else(|_| syn::Field::parse_unnamed.parse2(field))
+        .expect("Invalid test input (field)")
+}
+
+fn test_process_field(
+    vis: TokenStream,
+    input_attrs_ts: TokenStream,
+    field: TokenStream,
+    expect: TokenStream,
+) {
+    test_process_parsed_field(vis, input_attrs_ts, parse_field(field), 0, false, expect);
+}
+
+/// Returns the first line of each error reported for a field.
+fn field_errors(input_attrs_ts: TokenStream, field: TokenStream) -> Vec<String> {
+    let input_attrs = parse_input_attrs(quote!(pub), input_attrs_ts);
+    let field = parse_field(field);
+
+    let mut methods = TokenStream::new();
+    let mut errors = Errors::default();
+    process_field(&field, 0, false, &input_attrs, &mut methods, &mut errors);
+    let err = errors.finish().expect_err("Field should be rejected");
+    err.into_iter().map(|err| first_line(&err)).collect()
+}
+
+fn test_process_union_field(
+    vis: TokenStream,
+    input_attrs_ts: TokenStream,
+    field: TokenStream,
+    expect: TokenStream,
+) {
+    test_process_parsed_field(vis, input_attrs_ts, parse_field(field), 0, true, expect);
+}
+
+fn test_process_tuple_field(
+    vis: TokenStream,
+    input_attrs_ts: TokenStream,
+    field: TokenStream,
+    index: usize,
+    expect: TokenStream,
+) {
+    test_process_parsed_field(vis, input_attrs_ts, parse_field(field), index, false, expect);
+}
+
+fn test_process_parsed_field(
+    vis: TokenStream,
+    input_attrs_ts: TokenStream,
+    field: syn::Field,
+    index: usize,
+    is_union: bool,
+    expect: TokenStream,
+) {
+    let input_attrs = parse_input_attrs(vis, input_attrs_ts);
+
+    let mut methods = TokenStream::new();
+    let mut errors = Errors::default();
+    process_field(&field, index, is_union, &input_attrs, &mut methods, &mut errors);
+    errors.finish().expect("Error processing field");
+
+    if !token_stream_equals(expect.clone(), methods.clone()) {
+        panic!("Expected:\n{}\n\nGot:\n{}", expect, methods);
+    }
+}
+
+fn test_process_variant(
+    input_attrs_ts: TokenStream,
+    variant: TokenStream,
+    variant_count: usize,
+    expect: TokenStream,
+) {
+    let input_attrs = parse_input_attrs(quote!(pub), input_attrs_ts);
+
+    let variant = syn::parse2::<syn::Variant>(variant).expect("Invalid test input (variant)");
+
+    let mut methods = TokenStream::new();
+    let mut errors = Errors::default();
+    enums::process_variant(&variant, variant_count, &input_attrs, &mut methods, &mut errors);
+    errors.finish().expect("Error processing variant");
+
+    if !token_stream_equals(expect.clone(), methods.clone()) {
+        panic!("Expected:\n{}\n\nGot:\n{}", expect, methods);
+    }
+}
+
+/// The assertion generated at the start of the getter of a `copy` field.
+fn copy_assertion(field: &str, ty: TokenStream) -> TokenStream {
+    let message =
+        format!("field `{}` is marked `copy` but `{{Self}}` does not implement `Copy`", field);
+    quote! {
+        #[diagnostic::on_unimplemented(
+            message = #message,
+            label = "this type is not `Copy`",
+            note = "use `#[gusket(clone)]` to return a clone instead",
+        )]
+        trait AssertCopy {}
+        impl<T: ?::core::marker::Sized + ::core::marker::Copy> AssertCopy for T {}
+        const fn assert_copy<T: ?::core::marker::Sized + AssertCopy>() {}
+        assert_copy::<#ty>();
+    }
+}
+
+/// Returns the error message without the list of valid attributes.
+fn first_line(err: &syn::Error) -> String {
+    err.to_string().lines().next().unwrap_or_default().to_string()
+}
+
+fn parse_enum(input: TokenStream) -> (InputAttrs, syn::DataEnum) {
+    let input = syn::parse2::<syn::DeriveInput>(input).expect("Invalid test input (enum)");
+
+    let mut input_attrs = InputAttrs::new(&input.vis);
+    for attr in &input.attrs {
+        input_attrs.apply(attr).expect("Invalid test input (container attributes)");
     }
 
-    let field = syn::parse2::<NamedFieldParse>(field).expect("Invalid test input (field)").0;
+    match input.data {
+        syn::Data::Enum(data) => (input_attrs, data),
+        _ => panic!("Invalid test input (enum)"),
+    }
+}
+
+fn test_process_shared_fields(input: TokenStream, expect: TokenStream) {
+    let (input_attrs, data) = parse_enum(input);
 
     let mut methods = TokenStream::new();
-    process_field(&field, &input_attrs, &mut methods).expect("Error processing field");
+    let mut errors = Errors::default();
+    enums::process_shared_fields(&data, &input_attrs, &mut methods, &mut errors);
+    errors.finish().expect("Error processing shared fields");
 
     if !token_stream_equals(expect.clone(), methods.clone()) {
         panic!("Expected:\n{}\n\nGot:\n{}", expect, methods);
     }
 }
 
+fn test_process_shared_fields_error(input: TokenStream, expect: &str) {
+    let (input_attrs, data) = parse_enum(input);
+
+    let mut methods = TokenStream::new();
+    let mut errors = Errors::default();
+    enums::process_shared_fields(&data, &input_attrs, &mut methods, &mut errors);
+    let err = errors.finish().expect_err("Shared fields should be rejected");
+    assert_eq!(err.to_string(), expect);
+}
+
 #[test]
 fn test_default() {
     test_process_field(
@@ -393,6 +521,7 @@ fn test_container_vis_override_field_vis_override() {
 
 #[test]
 fn test_copy() {
+    let assertion = copy_assertion("foo", quote!(Bar));
     test_process_field(
         quote!(pub(in some::module)),
         quote! {},
@@ -404,6 +533,7 @@ fn test_copy() {
             #[must_use = "Getters have no side effect"]
             #[inline(always)]
             pub(in some::module) fn foo(&self) -> Bar {
+                #assertion
                 self.foo
             }
 
@@ -420,3 +550,1714 @@ fn test_copy() {
         },
     );
 }
+
+#[test]
+fn test_tuple_default() {
+    test_process_tuple_field(
+        quote!(pub(in some::module)),
+        quote! {},
+        quote! {
+            #[gusket]
+            Bar
+        },
+        1,
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn _1(&self) -> &Bar {
+                &self.1
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn _1_mut(&mut self) -> &mut Bar {
+                &mut self.1
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_1(&mut self, _1: Bar) {
+                self.1 = _1;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_tuple_name() {
+    let assertion = copy_assertion("0", quote!(Bar));
+    test_process_tuple_field(
+        quote!(pub(in some::module)),
+        quote! {},
+        quote! {
+            #[gusket(name = foo, copy)]
+            Bar
+        },
+        0,
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> Bar {
+                #assertion
+                self.0
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo_mut(&mut self) -> &mut Bar {
+                &mut self.0
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_foo(&mut self, foo: Bar) {
+                self.0 = foo;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_newtype() {
+    test_process_tuple_field(
+        quote!(pub(in some::module)),
+        quote! {
+            #[gusket(newtype)]
+        },
+        quote! {
+            Bar
+        },
+        0,
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn inner(&self) -> &Bar {
+                &self.0
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn inner_mut(&mut self) -> &mut Bar {
+                &mut self.0
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_inner(&mut self, inner: Bar) {
+                self.0 = inner;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_newtype_immut_field() {
+    let output = crate::gusket_impl(quote! {
+        #[gusket(newtype)]
+        struct Id(#[gusket(immut)] u64);
+    })
+    .to_string();
+
+    assert!(output.contains("AsRef"));
+    assert!(!output.contains("AsMut"), "{}", output);
+}
+
+#[test]
+fn test_newtype_auto_doc() {
+    let output = crate::gusket_impl(quote! {
+        #[gusket(newtype, auto_doc)]
+        struct Id(u64);
+    })
+    .to_string();
+
+    assert!(output.contains("\" Returns the inner value, consuming the newtype.\""), "{}", output);
+}
+
+#[test]
+fn test_has_uncovered_param() {
+    let generics: syn::Generics = syn::parse_quote!(<'a, T, U>);
+
+    for ty in [
+        quote!(T),
+        quote!(&'a T),
+        quote!(Box<U>),
+        quote!(core::pin::Pin<Box<T>>),
+        quote!(T::Item),
+        quote!(<U as Iterator>::Item),
+    ] {
+        let parsed: syn::Type = syn::parse2(ty.clone()).expect("Invalid test input (type)");
+        assert!(crate::types::has_uncovered_param(&parsed, &generics), "{}", ty);
+    }
+
+    for ty in [
+        quote!(u32),
+        quote!(Vec<T>),
+        quote!(Box<Vec<T>>),
+        quote!((T, U)),
+        quote!([T; 2]),
+        quote!(&'a str),
+    ] {
+        let parsed: syn::Type = syn::parse2(ty.clone()).expect("Invalid test input (type)");
+        assert!(!crate::types::has_uncovered_param(&parsed, &generics), "{}", ty);
+    }
+}
+
+#[test]
+fn test_variant_unit() {
+    test_process_variant(
+        quote! {},
+        quote! {
+            #[gusket]
+            HttpRequest
+        },
+        2,
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn is_http_request(&self) -> bool {
+                ::core::matches!(self, Self::HttpRequest { .. })
+            }
+        },
+    );
+}
+
+#[test]
+fn test_variant_tuple() {
+    test_process_variant(
+        quote! {},
+        quote! {
+            #[gusket(vis = pub(crate))]
+            Ipv4Addr(u32)
+        },
+        2,
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(crate) fn is_ipv4_addr(&self) -> bool {
+                ::core::matches!(self, Self::Ipv4Addr { .. })
+            }
+
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(crate) fn as_ipv4_addr(&self) -> ::core::option::Option<&u32> {
+                match self {
+                    Self::Ipv4Addr(field_0) => ::core::option::Option::Some(field_0),
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(crate) fn as_ipv4_addr_mut(&mut self) -> ::core::option::Option<&mut u32> {
+                match self {
+                    Self::Ipv4Addr(field_0) => ::core::option::Option::Some(field_0),
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[must_use = "Consuming the enum has no side effect"]
+            #[inline(always)]
+            pub(crate) fn into_ipv4_addr(self) -> ::core::result::Result<u32, Self> {
+                match self {
+                    Self::Ipv4Addr(field_0) => ::core::result::Result::Ok(field_0),
+                    other => ::core::result::Result::Err(other),
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn test_variant_auto_doc() {
+    test_process_variant(
+        quote! {
+            #[gusket(immut, auto_doc)]
+        },
+        quote! {
+            /// Docs
+            #[gusket]
+            Ipv4Addr(u32)
+        },
+        2,
+        quote! {
+            #[doc = " Returns `true` if `self` is the `Ipv4Addr` variant."]
+            #[doc = ""]
+            /// Docs
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn is_ipv4_addr(&self) -> bool {
+                ::core::matches!(self, Self::Ipv4Addr { .. })
+            }
+
+            #[doc = " Returns a reference to the contents of the `Ipv4Addr` variant, if `self` is that variant."]
+            #[doc = ""]
+            /// Docs
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn as_ipv4_addr(&self) -> ::core::option::Option<&u32> {
+                match self {
+                    Self::Ipv4Addr(field_0) => ::core::option::Option::Some(field_0),
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[doc = " Returns the contents of the `Ipv4Addr` variant, or `self` if it is another variant."]
+            #[doc = ""]
+            /// Docs
+            #[must_use = "Consuming the enum has no side effect"]
+            #[inline(always)]
+            pub fn into_ipv4_addr(self) -> ::core::result::Result<u32, Self> {
+                match self {
+                    Self::Ipv4Addr(field_0) => ::core::result::Result::Ok(field_0),
+                    other => ::core::result::Result::Err(other),
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn test_variant_named_immut_single() {
+    test_process_variant(
+        quote! {
+            #[gusket(all, immut)]
+        },
+        quote! {
+            HTTPRequest { foo: Foo, bar: Bar }
+        },
+        1,
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn is_http_request(&self) -> bool {
+                ::core::matches!(self, Self::HTTPRequest { .. })
+            }
+
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn as_http_request(&self) -> ::core::option::Option<(&Foo, &Bar)> {
+                match self {
+                    Self::HTTPRequest { foo, bar } => ::core::option::Option::Some((foo, bar)),
+                }
+            }
+
+            #[must_use = "Consuming the enum has no side effect"]
+            #[inline(always)]
+            pub fn into_http_request(self) -> ::core::result::Result<(Foo, Bar), Self> {
+                match self {
+                    Self::HTTPRequest { foo, bar } => ::core::result::Result::Ok((foo, bar)),
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn test_shared_field() {
+    let assertion = copy_assertion("span", quote!(Span));
+    test_process_shared_fields(
+        quote! {
+            pub enum Foo {
+                Bar { #[gusket(shared, copy)] span: Span, bar: u32 },
+                Qux { span: Span },
+            }
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn span(&self) -> Span {
+                #assertion
+                match self {
+                    Self::Bar { span: field, .. } | Self::Qux { span: field, .. } => { *field }
+                }
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub fn span_mut(&mut self) -> &mut Span {
+                match self {
+                    Self::Bar { span: field, .. } | Self::Qux { span: field, .. } => { &mut *field }
+                }
+            }
+
+            #[inline(always)]
+            pub fn set_span(&mut self, span: Span) {
+                match self {
+                    Self::Bar { span: field, .. } | Self::Qux { span: field, .. } => { *field = span; }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn test_shared_field_missing() {
+    test_process_shared_fields_error(
+        quote! {
+            pub enum Foo {
+                Bar { #[gusket(shared)] span: Span },
+                Qux(Span),
+            }
+        },
+        "Variant `Qux` is missing the shared field `span`",
+    );
+}
+
+#[test]
+fn test_union() {
+    test_process_union_field(
+        quote!(pub(in some::module)),
+        quote! {},
+        quote! {
+            #[gusket]
+            foo: ManuallyDrop<Bar>
+        },
+        quote! {
+            #[doc = ""]
+            #[doc = " # Safety"]
+            #[doc = " `foo` must be the active field of the union."]
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) unsafe fn foo(&self) -> &ManuallyDrop<Bar> {
+                unsafe { &self.foo }
+            }
+
+            #[doc = ""]
+            #[doc = " # Safety"]
+            #[doc = " `foo` must be the active field of the union."]
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) unsafe fn foo_mut(&mut self) -> &mut ManuallyDrop<Bar> {
+                unsafe { &mut self.foo }
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_foo(&mut self, foo: ManuallyDrop<Bar>) {
+                self.foo = foo;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_union_copy() {
+    let assertion = copy_assertion("foo", quote!(Bar));
+    test_process_union_field(
+        quote!(pub(in some::module)),
+        quote! {},
+        quote! {
+            #[gusket(copy)]
+            foo: Bar
+        },
+        quote! {
+            #[doc = ""]
+            #[doc = " # Safety"]
+            #[doc = " `foo` must be the active field of the union."]
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) unsafe fn foo(&self) -> Bar {
+                #assertion
+                unsafe { self.foo }
+            }
+
+            #[doc = ""]
+            #[doc = " # Safety"]
+            #[doc = " `foo` must be the active field of the union."]
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) unsafe fn foo_mut(&mut self) -> &mut Bar {
+                unsafe { &mut self.foo }
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_foo(&mut self, foo: Bar) {
+                self.foo = foo;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_deref() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {},
+        quote! {
+            #[gusket(deref, immut)]
+            foo: String
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> &str {
+                &self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_deref_explicit() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {},
+        quote! {
+            #[gusket(deref = Qux, immut)]
+            foo: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> &Qux {
+                &self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_container_deref() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {
+            #[gusket(deref)]
+        },
+        quote! {
+            #[gusket]
+            foo: Vec<Bar>
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> &[Bar] {
+                &self.foo
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo_mut(&mut self) -> &mut Vec<Bar> {
+                &mut self.foo
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_foo(&mut self, foo: Vec<Bar>) {
+                self.foo = foo;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_container_deref_array() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {
+            #[gusket(deref, immut)]
+        },
+        quote! {
+            #[gusket]
+            foo: [Bar; 4]
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> &[Bar; 4] {
+                &self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_option() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {},
+        quote! {
+            #[gusket(option(has, take, clear, get_or_insert_with, into))]
+            foo: Option<Bar>
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> ::core::option::Option<&Bar> {
+                ::core::option::Option::as_ref(&self.foo)
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo_mut(&mut self) -> &mut Option<Bar> {
+                &mut self.foo
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_foo(&mut self, foo: impl ::core::convert::Into<Option<Bar> >) {
+                self.foo = ::core::convert::Into::into(foo);
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn take_foo(&mut self) -> Option<Bar> {
+                ::core::option::Option::take(&mut self.foo)
+            }
+
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn has_foo(&self) -> bool {
+                ::core::option::Option::is_some(&self.foo)
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn clear_foo(&mut self) {
+                self.foo = ::core::option::Option::None;
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn foo_or_insert_with(
+                &mut self,
+                f: impl ::core::ops::FnOnce() -> Bar,
+            ) -> &mut Bar {
+                ::core::option::Option::get_or_insert_with(&mut self.foo, f)
+            }
+        },
+    );
+}
+
+#[test]
+fn test_option_deref() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {
+            #[gusket(immut, deref)]
+        },
+        quote! {
+            #[gusket(option)]
+            foo: Option<String>
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> ::core::option::Option<&str> {
+                ::core::option::Option::as_deref(&self.foo)
+            }
+        },
+    );
+}
+
+#[test]
+fn test_clone() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {
+            #[gusket(immut)]
+        },
+        quote! {
+            #[gusket(clone)]
+            foo: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> Bar {
+                ::core::clone::Clone::clone(&self.foo)
+            }
+        },
+    );
+}
+
+#[test]
+fn test_container_clone_field_copy() {
+    let assertion = copy_assertion("foo", quote!(Bar));
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {
+            #[gusket(immut, clone)]
+        },
+        quote! {
+            #[gusket(copy)]
+            foo: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> Bar {
+                #assertion
+                self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_clone_copy_conflict() {
+    assert_eq!(
+        field_errors(
+            quote! {},
+            quote! {
+                #[gusket(copy, clone)]
+                foo: Bar
+            },
+        ),
+        ["`clone` cannot be combined with `copy`"],
+    );
+}
+
+#[test]
+fn test_chain() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {},
+        quote! {
+            #[gusket(chain)]
+            foo: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> &Bar {
+                &self.foo
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo_mut(&mut self) -> &mut Bar {
+                &mut self.foo
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_foo(&mut self, foo: Bar) -> &mut Self {
+                self.foo = foo;
+                self
+            }
+        },
+    );
+}
+
+#[test]
+fn test_with_immut() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {
+            #[gusket(immut, with_vis = pub(crate))]
+        },
+        quote! {
+            #[gusket(with)]
+            foo: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> &Bar {
+                &self.foo
+            }
+
+            #[must_use = "Withers return the modified value"]
+            #[inline(always)]
+            pub(crate) fn with_foo(mut self, foo: Bar) -> Self {
+                self.foo = foo;
+                self
+            }
+        },
+    );
+}
+
+#[test]
+fn test_container_into() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {
+            #[gusket(into)]
+        },
+        quote! {
+            #[gusket(with)]
+            foo: Vec<T>
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> &Vec<T> {
+                &self.foo
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo_mut(&mut self) -> &mut Vec<T> {
+                &mut self.foo
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_foo(&mut self, foo: impl ::core::convert::Into<Vec<T> >) {
+                self.foo = ::core::convert::Into::into(foo);
+            }
+
+            #[must_use = "Withers return the modified value"]
+            #[inline(always)]
+            pub(in some::module) fn with_foo(mut self, foo: impl ::core::convert::Into<Vec<T> >) -> Self {
+                self.foo = ::core::convert::Into::into(foo);
+                self
+            }
+        },
+    );
+}
+
+#[test]
+fn test_replace_take_update() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {
+            #[gusket(take)]
+        },
+        quote! {
+            #[gusket(replace, update)]
+            foo: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> &Bar {
+                &self.foo
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo_mut(&mut self) -> &mut Bar {
+                &mut self.foo
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_foo(&mut self, foo: Bar) {
+                self.foo = foo;
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn replace_foo(&mut self, foo: Bar) -> Bar {
+                ::core::mem::replace(&mut self.foo, foo)
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn take_foo(&mut self) -> Bar {
+                ::core::mem::take(&mut self.foo)
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn update_foo(&mut self, f: impl ::core::ops::FnOnce(&mut Bar)) {
+                f(&mut self.foo)
+            }
+        },
+    );
+}
+
+#[test]
+fn test_select_get_set() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {},
+        quote! {
+            #[gusket(get, set)]
+            foo: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub(in some::module) fn foo(&self) -> &Bar {
+                &self.foo
+            }
+
+            #[inline(always)]
+            pub(in some::module) fn set_foo(&mut self, foo: Bar) {
+                self.foo = foo;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_container_select_set_only() {
+    test_process_field(
+        quote!(pub(in some::module)),
+        quote! {
+            #[gusket(set)]
+        },
+        quote! {
+            #[gusket]
+            foo: Bar
+        },
+        quote! {
+            #[inline(always)]
+            pub(in some::module) fn set_foo(&mut self, foo: Bar) {
+                self.foo = foo;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_select_mutability_conflict() {
+    for (field, expect) in [
+        (quote!(#[gusket(get, mut)] foo: Bar), "`mut` cannot be combined with `get`"),
+        (quote!(#[gusket(mut, get)] foo: Bar), "`get` cannot be combined with `mut`"),
+    ] {
+        assert_eq!(field_errors(quote! {}, field), [expect]);
+    }
+
+    for (attr, expect) in [
+        (quote!(#[gusket(set, immut)]), "`immut` cannot be combined with `set`"),
+        (quote!(#[gusket(immut, set)]), "`set` cannot be combined with `immut`"),
+    ] {
+        let attr: syn::Attribute = syn::parse_quote!(#attr);
+        let err = match InputAttrs::new(&syn::Visibility::Inherited).apply(&attr) {
+            Ok(()) => panic!("Attribute should be rejected"),
+            Err(err) => err,
+        };
+        assert_eq!(first_line(&err), expect);
+    }
+}
+
+#[test]
+fn test_accessor_vis() {
+    test_process_field(
+        quote!(),
+        quote! {
+            #[gusket(mut_vis = pub(super))]
+        },
+        quote! {
+            #[gusket(get_vis = pub, set_vis = pub(crate))]
+            foo: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn foo(&self) -> &Bar {
+                &self.foo
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub(super) fn foo_mut(&mut self) -> &mut Bar {
+                &mut self.foo
+            }
+
+            #[inline(always)]
+            pub(crate) fn set_foo(&mut self, foo: Bar) {
+                self.foo = foo;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_rename() {
+    test_process_field(
+        quote!(pub),
+        quote! {},
+        quote! {
+            #[gusket(rename = kind, setter = assign_kind)]
+            r#type: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn kind(&self) -> &Bar {
+                &self.r#type
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub fn kind_mut(&mut self) -> &mut Bar {
+                &mut self.r#type
+            }
+
+            #[inline(always)]
+            pub fn assign_kind(&mut self, kind: Bar) {
+                self.r#type = kind;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_raw_ident() {
+    test_process_field(
+        quote!(pub),
+        quote! {},
+        quote! {
+            #[gusket(getter = get_type, mut_getter = type_ref_mut)]
+            r#type: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn get_type(&self) -> &Bar {
+                &self.r#type
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub fn type_ref_mut(&mut self) -> &mut Bar {
+                &mut self.r#type
+            }
+
+            #[inline(always)]
+            pub fn set_type(&mut self, r#type: Bar) {
+                self.r#type = r#type;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_naming_template() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(getter_prefix = "get_", mut_suffix = "_ref", setter_prefix = "put_")]
+        },
+        quote! {
+            #[gusket]
+            r#type: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn get_type(&self) -> &Bar {
+                &self.r#type
+            }
+
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub fn type_ref(&mut self) -> &mut Bar {
+                &mut self.r#type
+            }
+
+            #[inline(always)]
+            pub fn put_type(&mut self, r#type: Bar) {
+                self.r#type = r#type;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_naming_bool_prefix() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(immut, bool_prefix = "is_")]
+        },
+        quote! {
+            #[gusket(copy)]
+            enabled: bool
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn is_enabled(&self) -> bool {
+                self.enabled
+            }
+        },
+    );
+}
+
+#[test]
+fn test_naming_keyword() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(immut, getter_suffix = "n")]
+        },
+        quote! {
+            #[gusket]
+            r#i: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn r#in(&self) -> &Bar {
+                &self.r#i
+            }
+        },
+    );
+}
+
+#[test]
+fn test_naming_reserved() {
+    assert_eq!(
+        field_errors(
+            quote! {
+                #[gusket(immut, getter_suffix = "er")]
+            },
+            quote! {
+                #[gusket]
+                sup: Bar
+            },
+        ),
+        ["The method name `super` is reserved, use different naming affixes or rename the \
+          accessor"],
+    );
+
+    // Accessors that are not generated are not named.
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(immut, setter_prefix = "s", setter_suffix = "f")]
+        },
+        quote! {
+            #[gusket]
+            el: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn el(&self) -> &Bar {
+                &self.el
+            }
+        },
+    );
+}
+
+#[test]
+fn test_naming_invalid_affix() {
+    let attr: syn::Attribute = syn::parse_quote!(#[gusket(getter_prefix = "get-")]);
+    let err = match InputAttrs::new(&syn::Visibility::Inherited).apply(&attr) {
+        Ok(()) => panic!("Affix should be rejected"),
+        Err(err) => err,
+    };
+    assert_eq!(err.to_string(), "Naming prefixes and suffixes must form valid identifiers");
+}
+
+#[test]
+fn test_const() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(immut)]
+        },
+        quote! {
+            #[gusket(const, copy)]
+            foo: u32
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub const fn foo(&self) -> u32 {
+                self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_container_const_skips_clone() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(immut, const)]
+        },
+        quote! {
+            #[gusket(clone)]
+            foo: Bar
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn foo(&self) -> Bar {
+                ::core::clone::Clone::clone(&self.foo)
+            }
+        },
+    );
+}
+
+#[test]
+fn test_const_clone_error() {
+    assert_eq!(
+        field_errors(
+            quote! {},
+            quote! {
+                #[gusket(const, clone)]
+                foo: Bar
+            },
+        ),
+        ["`const` cannot be combined with `clone`"],
+    );
+}
+
+#[test]
+fn test_forward_cfg() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(immut)]
+        },
+        quote! {
+            #[gusket]
+            #[cfg(feature = "x")]
+            #[deprecated]
+            foo: Bar
+        },
+        quote! {
+            #[cfg(feature = "x")]
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn foo(&self) -> &Bar {
+                &self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_forward_attrs() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(immut)]
+        },
+        quote! {
+            /// Docs
+            #[gusket(forward_attrs)]
+            #[cfg_attr(feature = "x", allow(unused))]
+            #[deprecated]
+            #[serde(skip)]
+            foo: Bar
+        },
+        quote! {
+            /// Docs
+            #[cfg_attr(feature = "x", allow(unused))]
+            #[deprecated]
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn foo(&self) -> &Bar {
+                &self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_auto_doc() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(auto_doc)]
+        },
+        quote! {
+            #[gusket]
+            foo: Bar
+        },
+        quote! {
+            #[doc = " Returns a reference to the `foo` field."]
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn foo(&self) -> &Bar {
+                &self.foo
+            }
+
+            #[doc = " Returns a mutable reference to the `foo` field."]
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub fn foo_mut(&mut self) -> &mut Bar {
+                &mut self.foo
+            }
+
+            #[doc = " Sets the `foo` field."]
+            #[inline(always)]
+            pub fn set_foo(&mut self, foo: Bar) {
+                self.foo = foo;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_auto_doc_documented() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(immut, auto_doc)]
+        },
+        quote! {
+            /// Docs
+            #[gusket]
+            foo: Bar
+        },
+        quote! {
+            #[doc = " Returns a reference to the `foo` field."]
+            #[doc = ""]
+            /// Docs
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn foo(&self) -> &Bar {
+                &self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_doc_templates() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(get_doc = "Gets {field}.", doc = "Accesses {field}.")]
+        },
+        quote! {
+            /// Docs
+            #[gusket(set_doc = "Overwrites {field}.")]
+            r#type: Bar
+        },
+        quote! {
+            #[doc = " Gets type."]
+            #[doc = ""]
+            /// Docs
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn r#type(&self) -> &Bar {
+                &self.r#type
+            }
+
+            #[doc = " Accesses type."]
+            #[doc = ""]
+            /// Docs
+            #[must_use = "Mutable getters have no side effect"]
+            #[inline(always)]
+            pub fn type_mut(&mut self) -> &mut Bar {
+                &mut self.r#type
+            }
+
+            #[doc = " Overwrites type."]
+            #[doc = ""]
+            /// Docs
+            #[inline(always)]
+            pub fn set_type(&mut self, r#type: Bar) {
+                self.r#type = r#type;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_method_policy() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(inline = hint, must_use = false)]
+        },
+        quote! {
+            #[gusket(track_caller)]
+            foo: Bar
+        },
+        quote! {
+            #[inline]
+            pub fn foo(&self) -> &Bar {
+                &self.foo
+            }
+
+            #[inline]
+            pub fn foo_mut(&mut self) -> &mut Bar {
+                &mut self.foo
+            }
+
+            #[inline]
+            #[track_caller]
+            pub fn set_foo(&mut self, foo: Bar) {
+                self.foo = foo;
+            }
+        },
+    );
+}
+
+#[test]
+fn test_method_policy_field() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(immut, inline = hint)]
+        },
+        quote! {
+            #[gusket(inline = never, must_use = "Unused foo")]
+            foo: Bar
+        },
+        quote! {
+            #[must_use = "Unused foo"]
+            #[inline(never)]
+            pub fn foo(&self) -> &Bar {
+                &self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_field_errors_accumulate() {
+    let field = parse_field(quote! {
+        #[gusket(immut, typo, copy, clone, vis = pub(nowhere), inline = 1, const)]
+        foo: u32
+    });
+    let input_attrs = parse_input_attrs(quote!(pub), quote! {});
+
+    let mut methods = TokenStream::new();
+    let mut errors = Errors::default();
+    process_field(&field, 0, false, &input_attrs, &mut methods, &mut errors);
+    let err = errors.finish().expect_err("Field should be rejected");
+    let messages: Vec<_> = err.into_iter().map(|err| first_line(&err)).collect();
+    assert_eq!(
+        messages,
+        [
+            "Unsupported attribute `typo`",
+            "expected `,`",
+            "expected identifier",
+            "`clone` cannot be combined with `copy`",
+        ]
+    );
+
+    // Valid options are still applied.
+    let expect = quote! {
+        #[must_use = "Getters have no side effect"]
+        #[inline(always)]
+        pub const fn foo(&self) -> u32 {
+            self.foo
+        }
+    };
+    if !token_stream_equals(methods.clone(), expect.clone()) {
+        panic!("Expected:\n{}\n\nGot:\n{}", expect, methods);
+    }
+}
+
+#[test]
+fn test_input_attr_errors_accumulate() {
+    let attr: syn::Attribute = syn::parse_quote!(#[gusket(immut, typo, inline = sometimes, all)]);
+    let mut input_attrs = InputAttrs::new(&syn::Visibility::Inherited);
+    let err = match input_attrs.apply(&attr) {
+        Ok(()) => panic!("Attribute should be rejected"),
+        Err(err) => err,
+    };
+    let messages: Vec<_> = err.into_iter().map(|err| first_line(&err)).collect();
+    assert_eq!(messages, ["Unsupported attribute `typo`", "Expected `always`, `hint` or `never`"]);
+
+    assert!(!input_attrs.accessors.set);
+    assert!(input_attrs.derive);
+}
+
+#[test]
+fn test_shared_field_errors_accumulate() {
+    let (input_attrs, data) = parse_enum(quote! {
+        #[gusket(immut)]
+        pub enum Foo {
+            Bar {
+                #[gusket(shared)]
+                span: Span,
+                #[gusket(shared)]
+                id: u32,
+                #[gusket]
+                extra: u32,
+            },
+            Qux {
+                span: Span,
+                id: u64,
+            },
+        }
+    });
+
+    let mut methods = TokenStream::new();
+    let mut errors = Errors::default();
+    enums::process_shared_fields(&data, &input_attrs, &mut methods, &mut errors);
+    let err = errors.finish().expect_err("Shared fields should be rejected");
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "Fields of enum variants can only be derived with `shared`",
+            "Shared field `id` must have type `u32` in every variant",
+        ]
+    );
+
+    // The valid shared field is still generated.
+    let expect = quote! {
+        #[must_use = "Getters have no side effect"]
+        #[inline(always)]
+        pub fn span(&self) -> &Span {
+            match self {
+                Self::Bar { span: field, .. } | Self::Qux { span: field, .. } => { &*field }
+            }
+        }
+    };
+    if !token_stream_equals(expect.clone(), methods.clone()) {
+        panic!("Expected:\n{}\n\nGot:\n{}", expect, methods);
+    }
+}
+
+#[test]
+fn test_unsupported_suggestion() {
+    assert_eq!(
+        field_errors(
+            quote! {},
+            quote! {
+                #[gusket(imut, cpy, all, frobnicate)]
+                foo: Bar
+            },
+        ),
+        [
+            "Unsupported attribute `imut`, did you mean `immut`?",
+            "Unsupported attribute `cpy`, did you mean `copy`?",
+            "`all` is only supported as a container attribute",
+            "Unsupported attribute `frobnicate`",
+        ],
+    );
+}
+
+#[test]
+fn test_unsupported_field_keyword_and_option() {
+    assert_eq!(
+        field_errors(
+            quote! {},
+            quote! {
+                #[gusket(type)]
+                #[gusket(option(hsa))]
+                #[gusket(option(frobnicate))]
+                foo: Option<Bar>
+            },
+        ),
+        [
+            "Unsupported attribute `type`, did you mean `take`?",
+            "Unsupported option accessor `hsa`, did you mean `has`?",
+            "Unsupported option accessor `frobnicate`",
+        ],
+    );
+
+    let attr: syn::Attribute = syn::parse_quote!(#[gusket(option(mut))]);
+    let err = attr.parse_args::<FieldAttr>().err().expect("Attribute should be rejected");
+    let message = err.to_string();
+    let mut lines = message.lines();
+    assert_eq!(lines.next(), Some("Unsupported option accessor `mut`"));
+    assert_eq!(
+        lines.next(),
+        Some("Valid option accessors are `take`, `clear`, `has`, `get_or_insert_with`, `into`"),
+    );
+}
+
+#[test]
+fn test_unsupported_valid_list() {
+    let attr: syn::Attribute = syn::parse_quote!(#[gusket(skip)]);
+    let err = match InputAttrs::new(&syn::Visibility::Inherited).apply(&attr) {
+        Ok(()) => panic!("Attribute should be rejected"),
+        Err(err) => err,
+    };
+    let message = err.to_string();
+    let mut lines = message.lines();
+    assert_eq!(lines.next(), Some("`skip` is only supported as a field attribute"));
+    assert!(lines.next().is_some_and(|line| {
+        line.starts_with("Valid container attributes are `vis`, `immut`,")
+    }));
+}
+
+#[test]
+fn test_unsupported_keyword() {
+    for (attr, expect) in [
+        (quote!(#[gusket(mut)]), "`mut` is only supported as a field attribute"),
+        (quote!(#[gusket(ref)]), "`ref` is only supported as a field attribute"),
+    ] {
+        let attr: syn::Attribute = syn::parse_quote!(#attr);
+        let err = match InputAttrs::new(&syn::Visibility::Inherited).apply(&attr) {
+            Ok(()) => panic!("Attribute should be rejected"),
+            Err(err) => err,
+        };
+        assert_eq!(first_line(&err), expect);
+    }
+
+    let input_attrs = parse_input_attrs(quote!(pub), quote!());
+    let variant = syn::parse2::<syn::Variant>(quote! {
+        #[gusket(ref, const)]
+        Foo(Bar)
+    })
+    .expect("Invalid test input (variant)");
+    let mut errors = Errors::default();
+    enums::process_variant(&variant, 1, &input_attrs, &mut TokenStream::new(), &mut errors);
+
+    let err = errors.finish().expect_err("Keyword attributes should be rejected");
+    let messages: Vec<_> = err.into_iter().map(|err| first_line(&err)).collect();
+    assert_eq!(
+        messages,
+        [
+            "`ref` is only supported as a field attribute",
+            "`const` is only supported as a container attribute",
+        ],
+    );
+}
+
+#[test]
+fn test_duplicate_and_conflict() {
+    assert_eq!(
+        field_errors(
+            quote! {},
+            quote! {
+                #[gusket(copy, mut)]
+                #[gusket(copy, immut)]
+                foo: Bar
+            },
+        ),
+        ["Duplicate attribute `copy`", "`immut` cannot be combined with `mut`"],
+    );
+    assert_eq!(
+        field_errors(
+            quote! {},
+            quote! {
+                #[gusket(vis = pub(crate), skip, copy)]
+                foo: Bar
+            },
+        ),
+        ["`copy` cannot be combined with `skip`"],
+    );
+}
+
+#[test]
+fn test_redundant_warning() {
+    let field = parse_field(quote! {
+        #[gusket(vis = pub, skip)]
+        foo: Bar
+    });
+    let input_attrs = parse_input_attrs(quote!(pub), quote!(#[gusket(all)]));
+
+    let mut methods = TokenStream::new();
+    let mut errors = Errors::default();
+    process_field(&field, 0, false, &input_attrs, &mut methods, &mut errors);
+
+    let expect = quote! {
+        const _: () = {
+            #[deprecated(note = "`vis` has no effect on a skipped field")]
+            #[allow(non_camel_case_types)]
+            struct redundant_gusket_attribute;
+            let _ = redundant_gusket_attribute;
+        };
+    };
+    if !token_stream_equals(expect.clone(), errors.warnings.clone()) {
+        panic!("Expected:\n{}\n\nGot:\n{}", expect, errors.warnings);
+    }
+    errors.finish().expect("Redundant attributes should only warn");
+}
+
+#[test]
+fn test_deny_redundant() {
+    let input_attrs = parse_input_attrs(quote!(pub), quote!(#[gusket(immut, deny_redundant)]));
+    let mut methods = TokenStream::new();
+    let mut errors = Errors::default();
+
+    for field in [
+        quote! {
+            #[gusket(immut)]
+            foo: Bar
+        },
+        quote! {
+            #[gusket(skip)]
+            foo: Bar
+        },
+    ] {
+        let field = parse_field(field);
+        process_field(&field, 0, false, &input_attrs, &mut methods, &mut errors);
+    }
+
+    let variant = syn::parse2::<syn::Variant>(quote! {
+        #[gusket(vis = pub(crate), skip)]
+        Foo(Bar)
+    })
+    .expect("Invalid test input (variant)");
+    enums::process_variant(&variant, 1, &input_attrs, &mut methods, &mut errors);
+
+    assert!(errors.warnings.is_empty());
+    let err = errors.finish().expect_err("Redundant attributes should be rejected");
+    let messages: Vec<_> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "`immut` has no effect because the container is already immutable",
+            "`skip` has no effect because the container does not derive all fields",
+            "`vis` has no effect on a skipped variant",
+            "`skip` has no effect because the container does not derive all variants",
+        ],
+    );
+}
+
+#[test]
+fn test_auto_copy() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(all, immut, auto_copy)]
+        },
+        quote! {
+            foo: (&'a str, [core::num::NonZeroU32; 2], *const u8)
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn foo(&self) -> (&'a str, [core::num::NonZeroU32; 2], *const u8) {
+                self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_auto_copy_ref() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(immut, auto_copy)]
+        },
+        quote! {
+            #[gusket(ref)]
+            foo: u32
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn foo(&self) -> &u32 {
+                &self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_auto_copy_unrecognized() {
+    test_process_field(
+        quote!(pub),
+        quote! {
+            #[gusket(all, immut, auto_copy)]
+        },
+        quote! {
+            foo: (u32, Bar)
+        },
+        quote! {
+            #[must_use = "Getters have no side effect"]
+            #[inline(always)]
+            pub fn foo(&self) -> &(u32, Bar) {
+                &self.foo
+            }
+        },
+    );
+}
+
+#[test]
+fn test_is_copy() {
+    for ty in [
+        quote!(u8),
+        quote!(f64),
+        quote!(char),
+        quote!(&'a T),
+        quote!(*mut T),
+        quote!(NonZeroI64),
+        quote!(std::num::NonZero<u16>),
+        quote!(()),
+        quote!((bool, [i32; 4])),
+    ] {
+        let parsed: syn::Type = syn::parse2(ty.clone()).expect("Invalid test input (type)");
+        assert!(crate::types::is_copy(&parsed), "{}", ty);
+    }
+
+    for ty in [
+        quote!(String),
+        quote!(&'a mut T),
+        quote!(NonZeroFoo),
+        quote!(u32::Foo),
+        quote!([Vec<u8>; 2]),
+    ] {
+        let parsed: syn::Type = syn::parse2(ty.clone()).expect("Invalid test input (type)");
+        assert!(!crate::types::is_copy(&parsed), "{}", ty);
+    }
+}
@@ -0,0 +1,146 @@
+//! Syntactic inspection of field types.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Infers the borrowed form of common owned containers,
+/// which `&ty` coerces to through `Deref` or unsizing.
+///
+/// Arrays are only converted to slices if `include_arrays` is true.
+pub(crate) fn deref_target(ty: &syn::Type, include_arrays: bool) -> Option<TokenStream> {
+    if let syn::Type::Array(array) = ty {
+        let elem = &array.elem;
+        return include_arrays.then(|| quote!([#elem]));
+    }
+
+    let segment = last_segment(ty)?;
+    let target = match segment.ident.to_string().as_str() {
+        "String" => quote!(str),
+        "PathBuf" => quote!(::std::path::Path),
+        "OsString" => quote!(::std::ffi::OsStr),
+        "CString" => quote!(::core::ffi::CStr),
+        "Vec" => {
+            let elem = single_type_arg(segment)?;
+            quote!([#elem])
+        }
+        "Box" => {
+            let inner = single_type_arg(segment)?;
+            quote!(#inner)
+        }
+        _ => return None,
+    };
+    Some(target)
+}
+
+/// Returns `T` if `ty` is syntactically `Option<T>`.
+pub(crate) fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let segment = last_segment(ty)?;
+    if segment.ident == "Option" {
+        single_type_arg(segment)
+    } else {
+        None
+    }
+}
+
+/// Returns true if `ty` is syntactically `bool`.
+pub(crate) fn is_bool(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path.is_ident("bool"),
+        _ => false,
+    }
+}
+
+/// Returns true if `ty` is syntactically known to implement `Copy`,
+/// i.e. a primitive scalar, shared reference, raw pointer or `NonZero*` integer,
+/// or a tuple or array of these.
+pub(crate) fn is_copy(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(reference) => reference.mutability.is_none(),
+        syn::Type::Ptr(_) => true,
+        syn::Type::Array(array) => is_copy(&array.elem),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().all(is_copy),
+        syn::Type::Paren(paren) => is_copy(&paren.elem),
+        syn::Type::Group(group) => is_copy(&group.elem),
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            if path.get_ident().is_some_and(|ident| PRIMITIVES.iter().any(|name| ident == name)) {
+                return true;
+            }
+
+            let segment = match path.segments.last() {
+                Some(segment) => segment,
+                None => return false,
+            };
+            match &segment.arguments {
+                syn::PathArguments::None => {
+                    let ident = segment.ident.to_string();
+                    ident.strip_prefix("NonZero").is_some_and(|suffix| {
+                        PRIMITIVES.iter().any(|name| {
+                            name.starts_with(['u', 'i']) && name.eq_ignore_ascii_case(suffix)
+                        })
+                    })
+                }
+                _ => segment.ident == "NonZero" && single_type_arg(segment).is_some(),
+            }
+        }
+        _ => false,
+    }
+}
+
+/// The primitive scalar types.
+const PRIMITIVES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "f32",
+    "f64", "bool", "char",
+];
+
+/// Returns true if a type parameter appears in `ty` without being covered by a non-fundamental type,
+/// in which case the orphan rules forbid implementing foreign traits for `ty`.
+///
+/// Associated types of parameters are conservatively treated as uncovered.
+pub(crate) fn has_uncovered_param(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    match ty {
+        syn::Type::Reference(reference) => has_uncovered_param(&reference.elem, generics),
+        syn::Type::Paren(paren) => has_uncovered_param(&paren.elem, generics),
+        syn::Type::Group(group) => has_uncovered_param(&group.elem, generics),
+        syn::Type::Path(syn::TypePath { qself: Some(_), .. }) => true,
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            let first = match path.segments.first() {
+                Some(segment) => &segment.ident,
+                None => return false,
+            };
+            if generics.type_params().any(|param| *first == param.ident) {
+                return true;
+            }
+
+            // `Box<T>` and `Pin<T>` are fundamental and do not cover `T`.
+            let segment = match path.segments.last() {
+                Some(segment) => segment,
+                None => return false,
+            };
+            (segment.ident == "Box" || segment.ident == "Pin")
+                && single_type_arg(segment)
+                    .is_some_and(|inner| has_uncovered_param(inner, generics))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the last path segment of an unqualified path type.
+fn last_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path.segments.last(),
+        _ => None,
+    }
+}
+
+/// Returns the type argument of a path segment with exactly one generic argument.
+fn single_type_arg(segment: &syn::PathSegment) -> Option<&syn::Type> {
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+            match args.args.first()? {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -10,18 +10,162 @@
 //!
 //! # Comparison with [`getset`](https://github.com/Hoverbear/getset):
 //! - `gusket` only exposes one derive macro.
-//! No need to `derive(Getters, MutGetters, Setters)` all the time.
-//! This avoids accidentally forgetting some derives,
-//! e.g. writing `#[getset(get_copy)]` with only `#[derive(getset::Getters)]`
-//! will generate nothing without triggering a compile error.
+//!   No need to `derive(Getters, MutGetters, Setters)` all the time.
+//!   This avoids accidentally forgetting some derives,
+//!   e.g. writing `#[getset(get_copy)]` with only `#[derive(getset::Getters)]`
+//!   will generate nothing without triggering a compile error.
 //! - `gusket` uses the struct visibility by default.
-//! This means that the usual boilerplate
-//! `#[getset(get = "pub", get_mut = "pub", set = "pub")]`
-//! is simplified to just `#[gusket]`.
+//!   This means that the usual boilerplate
+//!   `#[getset(get = "pub", get_mut = "pub", set = "pub")]`
+//!   is simplified to just `#[gusket]`.
 //! - `gusket` generates code from the span of the field (not the derive call),
-//! so error messages are more readable.
+//!   so error messages are more readable.
 
-/// Derives getters and setters implementation for a struct.
+/// Derives getters and setters implementation for a struct or an enum.
+///
+/// By default, each field gets a getter `foo()`, a mutable getter `foo_mut()` and a setter `set_foo()`.
+/// `immut` removes the mutable getter and the setter, while `mut` restores them.
+/// `get`, `get_mut` and `set` select the generated accessors explicitly,
+/// e.g. `#[gusket(get, set)]` generates a getter and a setter without a mutable getter,
+/// so that invariants enforced by the setter cannot be bypassed.
+/// They cannot be combined with `mut` or `immut` on the same item.
+///
+/// `get_vis`, `mut_vis` and `set_vis` override the visibility of getters, mutable getters and setters respectively,
+/// e.g. `#[gusket(get_vis = pub, set_vis = pub(crate))]`.
+/// They can be specified on the container or on each field,
+/// and default to the field visibility set by `vis`.
+/// Accessors derived from setters (`replace_foo`, `take_foo`, `clear_foo`)
+/// and from mutable getters (`update_foo`, `foo_or_insert_with`) follow `set_vis` and `mut_vis`.
+///
+/// Fields of tuple structs are exposed as `_0()`, `_0_mut()` and `set_0()` by default.
+/// Use `#[gusket(name = foo)]` on a tuple field to generate `foo()`, `foo_mut()` and `set_foo()` instead.
+///
+/// `#[gusket(rename = kind)]` names the accessors of a field after `kind` instead of the field name,
+/// e.g. `kind()`, `kind_mut()` and `set_kind()`.
+/// `getter = ...`, `mut_getter = ...` and `setter = ...` override the name of a single accessor.
+/// Raw identifiers lose their `r#` prefix in prefixed or suffixed names,
+/// e.g. a field `r#type` gets `r#type()`, `type_mut()` and `set_type()`.
+///
+/// The container attributes `getter_prefix`, `getter_suffix`, `mut_prefix`, `mut_suffix`,
+/// `setter_prefix` and `setter_suffix` customize the accessor names,
+/// e.g. `#[gusket(getter_prefix = "get_")]` generates `get_foo()` instead of `foo()`.
+/// `bool_prefix` replaces `getter_prefix` for `bool` fields,
+/// e.g. `#[gusket(bool_prefix = "is_")]` generates `is_enabled()` for a field `enabled: bool`.
+/// Names that collide with a keyword are generated as raw identifiers,
+/// except `self`, `Self`, `super` and `crate`, which are rejected.
+///
+/// `#[gusket(copy)]` makes the getter return the field by value.
+/// The field type must implement `Copy`, which is checked with an error pointing at the type:
+/// ```compile_fail,E0277
+/// #[derive(gusket::Gusket)]
+/// struct Beta {
+///     #[gusket(copy)]
+///     foo: String,
+/// }
+/// ```
+/// The check emits `#[diagnostic::on_unimplemented]` into the deriving crate,
+/// so gusket requires rustc 1.78 or later.
+/// `#[gusket(auto_copy)]` on the container returns primitive scalars, shared references, raw pointers,
+/// `NonZero*` integers and tuples and arrays of these by value without `copy`.
+/// `#[gusket(ref)]` returns such a field by reference instead.
+///
+/// `#[gusket(clone)]` makes the getter return a clone of the field,
+/// which is useful for `Rc<T>` and `Arc<T>` handles.
+/// It cannot be combined with `copy` on the same field.
+/// `#[gusket(clone)]` on the container applies to every field
+/// without `copy`, `deref` or `option`.
+///
+/// `#[gusket(chain)]` on a field or the container makes setters return `&mut Self`,
+/// allowing calls like `value.set_foo(1).set_bar(2)`.
+///
+/// `#[gusket(with)]` on a field or the container generates `with_foo(self, foo) -> Self`,
+/// even for `immut` fields.
+/// Its visibility can be set separately with `#[gusket(with_vis = ...)]`.
+///
+/// `#[gusket(into)]` on a field or the container makes setters and withers
+/// accept `impl Into<T>` instead of `T`.
+///
+/// `#[gusket(replace)]`, `#[gusket(take)]` and `#[gusket(update)]` on a field or the container
+/// generate `replace_foo(foo) -> T` (returning the old value),
+/// `take_foo() -> T` (for `Default` fields)
+/// and `update_foo(|foo: &mut T| ...)` respectively.
+/// `replace` and `take` require the setter, and `update` requires the mutable getter.
+///
+/// `#[gusket(const)]` generates the getter as a `const fn`, e.g. for `copy` fields used in const contexts.
+/// It cannot be combined with `clone` or `deref`, which call trait methods.
+/// On the container, `const` applies to all getters except those of `clone` and `deref` fields.
+///
+/// With `#[gusket(forward_attrs)]` on the container or on a field,
+/// its `#[deprecated]`, `#[allow]` and `#[warn]` attributes are copied to all its generated methods,
+/// so that the accessors of a deprecated field are deprecated as well:
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// use gusket::Gusket;
+///
+/// #[derive(Gusket)]
+/// #[gusket(forward_attrs)]
+/// struct Beta {
+///     #[gusket]
+///     #[deprecated]
+///     foo: u32,
+/// }
+///
+/// fn x(beta: &Beta) -> &u32 { beta.foo() }
+/// ```
+///
+/// By default, the documentation of a field is copied to all its generated methods.
+/// `#[gusket(auto_doc)]` on the container prepends a summary like
+/// "Returns a reference to the `foo` field." to the documentation of each method,
+/// including the enum variant methods and `into_inner()`.
+/// `#[gusket(doc = "...")]` sets the summary of all methods,
+/// and `get_doc`, `mut_doc` and `set_doc` set the summary of the getter, the mutable getter and the setter.
+/// `{field}` in these templates is replaced by the field name.
+/// They can be specified on the container or on each field,
+/// and the field documentation follows the summary as a separate paragraph.
+///
+/// Generated methods are `#[inline(always)]` by default.
+/// `inline = hint` and `inline = never` generate `#[inline]` and `#[inline(never)]` instead.
+/// `must_use = false` removes `#[must_use]` from getters, and `must_use = "..."` replaces its message.
+/// `track_caller` adds `#[track_caller]` to setters and other mutating accessors.
+/// They can be specified on the container or on each field.
+///
+/// Attributes that have no effect, such as `vis` on a skipped field
+/// or `immut` on a field of an `immut` container, are reported as deprecation warnings.
+/// `#[gusket(deny_redundant)]` on the container reports them as errors instead.
+///
+/// `#[gusket(deref)]` makes the getter return the borrowed form of common owned containers:
+/// `String` as `&str`, `Vec<T>` and `[T; N]` as `&[T]`, `Box<T>` as `&T`,
+/// `PathBuf` as `&Path`, `OsString` as `&OsStr` and `CString` as `&CStr`.
+/// Other `Deref` targets can be specified with `#[gusket(deref = Target)]`.
+/// `#[gusket(deref)]` on the container applies to every recognized field type except arrays,
+/// and can be disabled for a field with `#[gusket(deref = false)]`.
+///
+/// `#[gusket(option)]` on an `Option<T>` field makes the getter return `Option<&T>`,
+/// or the borrowed form of `T` if combined with `deref`.
+/// Additional accessors can be requested with
+/// `#[gusket(option(has, take, clear, get_or_insert_with, into))]`,
+/// which generate `has_foo()`, `take_foo()` (same as `take`), `clear_foo()`, `foo_or_insert_with(f)`
+/// and a setter accepting `impl Into<Option<T>>` (same as `into`) respectively.
+///
+/// For enums, each variant derives `is_variant()`, `as_variant()`, `as_variant_mut()` and `into_variant()`,
+/// where `variant` is the snake case variant name.
+/// Variants with multiple fields are returned as tuples.
+/// Variant-level `#[gusket(vis = ..., immut, mut, skip)]` behaves like the field-level attributes.
+///
+/// A named variant field marked `#[gusket(shared)]` must be present with the same type in every variant.
+/// Its accessors are generated on the enum itself and match on `self`.
+/// The remaining field attributes are accepted on the marked occurrence.
+///
+/// For unions, getters, mutable getters and other accessors reading the field are `unsafe fn`s
+/// since the caller must ensure that the field is active.
+/// Setters and withers are safe, since assigning to a union field is always safe.
+///
+/// `#[gusket(newtype)]` on a single-field tuple struct generates
+/// `inner()`, `inner_mut()`, `set_inner()` and `into_inner()`,
+/// together with `AsRef`, `AsMut` and `From` conversions in both directions.
+/// `AsMut` is omitted if the inner field is `immut`,
+/// and `From<Self>` is not implemented for the inner type if it leaves a type parameter uncovered,
+/// e.g. `T`, `&T` or `Box<T>`, since the orphan rules forbid it.
 ///
 /// # Example
 /// ```rust
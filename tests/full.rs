@@ -1,5 +1,7 @@
-#![allow(clippy::blacklisted_name)]
-#![deny(dead_code, missing_docs)]
+//! Integration tests for the `Gusket` derive macro.
+
+#![allow(clippy::disallowed_names)]
+#![deny(dead_code, missing_docs, clippy::missing_safety_doc)]
 
 use gusket::Gusket;
 
@@ -49,8 +51,559 @@ fn use_beta(beta: Beta) {
     let _ = &beta.bar; // no getter method
 }
 
+#[derive(Default, Gusket)]
+#[gusket(all)]
+struct Gamma(#[gusket(name = foo)] String, #[gusket(copy, immut)] u32, #[gusket(skip)] bool);
+
+fn use_gamma(mut gamma: Gamma) {
+    let _: &String = gamma.foo();
+    let _: &mut String = gamma.foo_mut();
+    gamma.set_foo(String::new());
+
+    let _: u32 = gamma._1();
+
+    let _ = &gamma.2; // no getter method
+}
+
+#[derive(Default, Gusket)]
+#[gusket(newtype)]
+struct Delta(u64);
+
+#[derive(Default, Gusket)]
+#[gusket(newtype, immut)]
+struct Epsilon<T>(T);
+
+#[derive(Default, Gusket)]
+#[gusket(newtype, immut, deref)]
+struct BoxedEpsilon<T>(Box<T>);
+
+#[derive(Default, Gusket)]
+#[gusket(newtype, immut)]
+struct VecEpsilon<T>(Vec<T>);
+
+fn use_newtypes(mut delta: Delta, epsilon: Epsilon<String>) {
+    let _: &u64 = delta.inner();
+    let _: &mut u64 = delta.inner_mut();
+    delta.set_inner(1);
+    let _: &u64 = delta.as_ref();
+    let _: &mut u64 = delta.as_mut();
+    let delta = Delta::from(2u64);
+    let _: u64 = u64::from(delta);
+    let _: u64 = Delta::from(3u64).into_inner();
+
+    let _: &String = epsilon.inner();
+    let _: &String = epsilon.as_ref();
+    let _: String = epsilon.into_inner();
+
+    let boxed = BoxedEpsilon::from(Box::new(1u8));
+    let _: &u8 = boxed.inner();
+    let _: Box<u8> = boxed.into_inner();
+
+    let vec = VecEpsilon::from(vec![1u8]);
+    let _: &Vec<u8> = vec.inner();
+    let _: Vec<u8> = Vec::from(vec);
+    let _: Vec<u8> = VecEpsilon::from(vec![2u8]).into_inner();
+}
+
+#[derive(Gusket)]
+#[gusket(all)]
+enum Zeta {
+    Foo,
+    Bar(u32),
+    #[gusket(immut)]
+    Qux {
+        corge:  String,
+        grault: bool,
+    },
+    #[gusket(skip)]
+    Waldo(u32),
+}
+
+fn use_zeta(mut zeta: Zeta) {
+    let _: bool = zeta.is_foo();
+
+    let _: bool = zeta.is_bar();
+    let _: Option<&u32> = zeta.as_bar();
+    let _: Option<&mut u32> = zeta.as_bar_mut();
+
+    let _: bool = zeta.is_qux();
+    let _: Option<(&String, &bool)> = zeta.as_qux();
+
+    if let Zeta::Waldo(waldo) = zeta {
+        let _ = waldo; // no getter method
+        return;
+    }
+
+    let zeta = match zeta.into_bar() {
+        Ok(_) => return,
+        Err(zeta) => zeta,
+    };
+    let _: Result<(String, bool), Zeta> = zeta.into_qux();
+}
+
+#[derive(Gusket)]
+enum Eta {
+    Foo {
+        #[gusket(shared, copy)]
+        span: u32,
+        bar:  String,
+    },
+    Qux {
+        span: u32,
+    },
+}
+
+fn use_eta(mut eta: Eta) {
+    eta.set_span(eta.span() + 1);
+    *eta.span_mut() += 1;
+
+    if let Eta::Foo { bar, .. } = eta {
+        let _ = bar; // no getter method
+    }
+}
+
+#[derive(Gusket)]
+#[gusket(all)]
+#[repr(C)]
+union Theta {
+    #[gusket(copy)]
+    foo: u32,
+    bar: std::mem::ManuallyDrop<String>,
+}
+
+/// Test module for the generated safety documentation.
+pub mod theta {
+    use gusket::Gusket;
+
+    /// Test union for the generated safety documentation.
+    #[derive(Gusket)]
+    #[gusket(all, vis = pub, immut)]
+    pub union PubTheta {
+        foo: u32,
+    }
+}
+
+fn use_theta(mut theta: Theta) {
+    theta.set_foo(1);
+    let _: u32 = unsafe { theta.foo() };
+    let _: &mut u32 = unsafe { theta.foo_mut() };
+
+    theta.set_bar(std::mem::ManuallyDrop::new(String::new()));
+    let _: &std::mem::ManuallyDrop<String> = unsafe { theta.bar() };
+    unsafe { std::mem::ManuallyDrop::drop(theta.bar_mut()) };
+}
+
+#[derive(Default, Gusket)]
+#[gusket(all, immut, deref)]
+struct Iota {
+    foo:    String,
+    bar:    Vec<u32>,
+    qux:    Box<bool>,
+    corge:  std::path::PathBuf,
+    grault: std::ffi::OsString,
+    garply: std::ffi::CString,
+    #[gusket(deref)]
+    waldo:  [u8; 4],
+    #[gusket(deref = str)]
+    fred:   std::borrow::Cow<'static, str>,
+    #[gusket(deref = false)]
+    plugh:  String,
+}
+
+fn use_iota(iota: Iota) {
+    let _: &str = iota.foo();
+    let _: &[u32] = iota.bar();
+    let _: &bool = iota.qux();
+    let _: &std::path::Path = iota.corge();
+    let _: &std::ffi::OsStr = iota.grault();
+    let _: &std::ffi::CStr = iota.garply();
+    let _: &[u8] = iota.waldo();
+    let _: &str = iota.fred();
+    let _: &String = iota.plugh();
+}
+
+#[derive(Default, Gusket)]
+struct Kappa {
+    #[gusket(option(has, take, clear, get_or_insert_with, into))]
+    foo: Option<u32>,
+    #[gusket(option, deref, immut)]
+    bar: Option<String>,
+}
+
+fn use_kappa(mut kappa: Kappa) {
+    let _: Option<&u32> = kappa.foo();
+    kappa.set_foo(1);
+    kappa.set_foo(None);
+    let _: &mut Option<u32> = kappa.foo_mut();
+    let _: &mut u32 = kappa.foo_or_insert_with(|| 2);
+    let _: bool = kappa.has_foo();
+    let _: Option<u32> = kappa.take_foo();
+    kappa.clear_foo();
+
+    let _: Option<&str> = kappa.bar();
+}
+
+#[derive(Default, Gusket)]
+#[gusket(all, immut, clone)]
+struct Lambda {
+    foo: std::rc::Rc<String>,
+    #[gusket(copy)]
+    bar: u32,
+    #[gusket(deref)]
+    qux: String,
+}
+
+fn use_lambda(lambda: Lambda) {
+    let _: std::rc::Rc<String> = lambda.foo();
+    let _: u32 = lambda.bar();
+    let _: &str = lambda.qux();
+}
+
+#[derive(Default, Gusket)]
+#[gusket(chain)]
+struct Mu {
+    #[gusket(copy)]
+    foo: u32,
+    #[gusket(immut)]
+    bar: String,
+}
+
+fn use_mu(mut mu: Mu) {
+    mu.set_foo(1).set_foo(2);
+    mu.set_foo(3);
+    let _: u32 = mu.foo();
+    let _: &mut u32 = mu.foo_mut();
+    let _: &String = mu.bar();
+}
+
+#[derive(Default, Gusket)]
+#[gusket(all, immut, with)]
+struct Nu {
+    #[gusket(copy)]
+    foo: u32,
+    #[gusket(with_vis = pub(self))]
+    bar: String,
+}
+
+#[derive(Gusket)]
+#[gusket(immut)]
+enum Xi {
+    Foo {
+        #[gusket(shared, copy, with)]
+        foo: u32,
+    },
+    Bar {
+        foo: u32,
+    },
+}
+
+fn use_nu(nu: Nu, xi: Xi) {
+    let nu = nu.with_foo(1).with_bar(String::new());
+    let _: u32 = nu.foo();
+    let _: &String = nu.bar();
+
+    let xi = xi.with_foo(2);
+    let _: u32 = xi.foo();
+}
+
+#[derive(Default, Gusket)]
+struct Omicron<T: Clone> {
+    #[gusket(into)]
+    foo: String,
+    #[gusket(into, immut, with)]
+    bar: Vec<T>,
+}
+
+fn use_omicron(mut omicron: Omicron<u32>) {
+    omicron.set_foo("foo");
+    let _: &mut String = omicron.foo_mut();
+    let omicron = omicron.with_bar([1u32, 2]);
+    let _: &String = omicron.foo();
+    let _: &Vec<u32> = omicron.bar();
+}
+
+#[derive(Default, Gusket)]
+#[gusket(replace, take, update)]
+struct Pi {
+    #[gusket(into)]
+    foo: String,
+    #[gusket(copy, immut)]
+    bar: u32,
+}
+
+fn use_pi(mut pi: Pi) {
+    let _: String = pi.replace_foo("foo");
+    let _: String = pi.take_foo();
+    pi.update_foo(|foo| foo.push('a'));
+    pi.set_foo(String::new());
+    let _: &mut String = pi.foo_mut();
+    let _: &String = pi.foo();
+    let _: u32 = pi.bar();
+}
+
+#[derive(Default, Gusket)]
+#[gusket(get)]
+struct Rho {
+    #[gusket]
+    foo:   String,
+    #[gusket(get, set)]
+    bar:   u32,
+    #[gusket(set)]
+    qux:   String,
+    #[gusket(get_mut)]
+    corge: Vec<u32>,
+}
+
+fn use_rho(mut rho: Rho) {
+    let _: &String = rho.foo();
+    let _: &u32 = rho.bar();
+    rho.set_bar(1);
+    rho.set_qux(String::new());
+    let _: &mut Vec<u32> = rho.corge_mut();
+
+    let _ = &rho.qux; // no getter method
+}
+
+mod sigma {
+    use gusket::Gusket;
+
+    /// Test struct for per-accessor visibility.
+    #[derive(Default, Gusket)]
+    #[gusket(all, get_vis = pub, set_vis = pub(crate), mut_vis = pub(super))]
+    pub struct Sigma {
+        foo: u32,
+        #[gusket(set_vis = pub)]
+        bar: u32,
+    }
+
+    pub(crate) fn set_foo(sigma: &mut Sigma) { sigma.set_foo(1); }
+}
+
+fn use_sigma(mut sigma: sigma::Sigma) {
+    sigma::set_foo(&mut sigma);
+    *sigma.foo_mut() = 2;
+    sigma.set_bar(3);
+    *sigma.bar_mut() = 4;
+    let _: &u32 = sigma.foo();
+    let _: &u32 = sigma.bar();
+}
+
+#[derive(Default, Gusket)]
+#[gusket(all)]
+struct Tau {
+    r#type:      u32,
+    #[gusket(rename = state)]
+    inner_state: u32,
+    #[gusket(getter = get_kind, mut_getter = kind_ref_mut, setter = assign_kind)]
+    kind:        u32,
+}
+
+fn use_tau(mut tau: Tau) {
+    tau.set_type(1);
+    *tau.type_mut() = 2;
+    let _: &u32 = tau.r#type();
+
+    tau.set_state(3);
+    *tau.state_mut() = 4;
+    let _: &u32 = tau.state();
+
+    tau.assign_kind(5);
+    *tau.kind_ref_mut() = 6;
+    let _: &u32 = tau.get_kind();
+}
+
+#[derive(Default, Gusket)]
+#[gusket(all, getter_prefix = "get_", bool_prefix = "is_", setter_suffix = "_to")]
+struct Upsilon {
+    foo:     u32,
+    #[gusket(copy)]
+    enabled: bool,
+}
+
+fn use_upsilon(mut upsilon: Upsilon) {
+    upsilon.set_foo_to(1);
+    *upsilon.foo_mut() = 2;
+    let _: &u32 = upsilon.get_foo();
+
+    upsilon.set_enabled_to(true);
+    *upsilon.enabled_mut() = false;
+    let _: bool = upsilon.is_enabled();
+}
+
+#[derive(Gusket)]
+#[gusket(all, immut, const)]
+struct Phi {
+    #[gusket(copy)]
+    foo: u32,
+    bar: Option<u32>,
+    #[gusket(option)]
+    qux: Option<u32>,
+}
+
+const PHI: Phi = Phi { foo: 1, bar: None, qux: Some(2) };
+const PHI_FOO: u32 = PHI.foo();
+const PHI_BAR: &Option<u32> = PHI.bar();
+const PHI_QUX: Option<&u32> = PHI.qux();
+
+fn use_phi() {
+    assert_eq!(PHI_FOO, 1);
+    assert_eq!(PHI_BAR, &None);
+    assert_eq!(PHI_QUX, Some(&2));
+}
+
+#[deny(deprecated)]
+mod chi {
+    use gusket::Gusket;
+
+    #[derive(Default, Gusket)]
+    #[gusket(all, vis = pub(super), forward_attrs)]
+    pub(super) struct Chi {
+        foo: u32,
+        #[deprecated]
+        qux: u32,
+    }
+}
+
+#[allow(deprecated)]
+fn use_chi(mut chi: chi::Chi) {
+    chi.set_foo(1);
+    *chi.foo_mut() = 2;
+    let _: &u32 = chi.foo();
+
+    chi.set_qux(3);
+    *chi.qux_mut() = 4;
+    let _: &u32 = chi.qux();
+}
+
+/// Test module for generated documentation.
+pub mod psi {
+    use gusket::Gusket;
+
+    /// Test struct for generated documentation.
+    #[derive(Default, Gusket)]
+    #[gusket(all, vis = pub, auto_doc, with)]
+    pub struct Psi {
+        foo: u32,
+        #[gusket(option(has, clear))]
+        bar: Option<u32>,
+        #[gusket(set_doc = "Replaces the `{field}` value.")]
+        qux: u32,
+    }
+
+    /// Test newtype for generated documentation.
+    #[derive(Gusket)]
+    #[gusket(newtype, vis = pub, auto_doc)]
+    pub struct PsiId(u64);
+
+    /// Test enum for generated documentation.
+    #[derive(Gusket)]
+    #[gusket(all, vis = pub, auto_doc)]
+    pub enum PsiKind {
+        /// Documented variant.
+        Foo,
+        #[allow(missing_docs)]
+        Bar(u32),
+    }
+}
+
+fn use_psi(psi: psi::Psi) {
+    let mut psi = psi.with_foo(1).with_bar(Some(2)).with_qux(3);
+    psi.set_foo(1);
+    *psi.foo_mut() = 2;
+    let _: &u32 = psi.foo();
+
+    psi.set_bar(Some(3));
+    *psi.bar_mut() = None;
+    let _: Option<&u32> = psi.bar();
+    let _: bool = psi.has_bar();
+    psi.clear_bar();
+
+    psi.set_qux(4);
+    *psi.qux_mut() = 5;
+    let _: &u32 = psi.qux();
+
+    let mut id = psi::PsiId::from(1);
+    id.set_inner(2);
+    *id.inner_mut() = 3;
+    let _: &u64 = id.inner();
+    let _: u64 = id.into_inner();
+
+    let mut kind = psi::PsiKind::Bar(1);
+    let _: bool = kind.is_foo();
+    let _: bool = kind.is_bar();
+    let _: Option<&u32> = kind.as_bar();
+    let _: Option<&mut u32> = kind.as_bar_mut();
+    let _: Result<u32, psi::PsiKind> = kind.into_bar();
+}
+
+#[derive(Default, Gusket)]
+#[gusket(all, inline = never, must_use = "Omega getters are pure", track_caller)]
+struct Omega {
+    #[gusket(inline = hint, must_use = false)]
+    foo: u32,
+    bar: u32,
+}
+
+fn use_omega(mut omega: Omega) {
+    omega.set_foo(1);
+    *omega.foo_mut() = 2;
+    omega.foo();
+
+    omega.set_bar(3);
+    *omega.bar_mut() = 4;
+    let _: &u32 = omega.bar();
+}
+
+#[derive(Gusket)]
+#[gusket(all, immut, auto_copy)]
+struct Digamma<'a> {
+    foo:   u32,
+    bar:   (&'a str, [char; 2]),
+    #[gusket(ref)]
+    qux:   core::num::NonZeroU8,
+    corge: String,
+}
+
+fn use_digamma(digamma: Digamma) {
+    let _: u32 = digamma.foo();
+    let _: (&str, [char; 2]) = digamma.bar();
+    let _: &core::num::NonZeroU8 = digamma.qux();
+    let _: &String = digamma.corge();
+}
+
 #[test]
 fn test() {
     use_alpha(Alpha::default());
     use_beta(Beta::default());
+    use_gamma(Gamma::default());
+    use_newtypes(Delta::default(), Epsilon::default());
+    use_zeta(Zeta::Foo);
+    use_zeta(Zeta::Bar(1));
+    use_zeta(Zeta::Qux { corge: String::new(), grault: false });
+    use_zeta(Zeta::Waldo(1));
+    use_eta(Eta::Foo { span: 1, bar: String::new() });
+    use_eta(Eta::Qux { span: 1 });
+    use_theta(Theta { foo: 0 });
+    use_iota(Iota::default());
+    use_kappa(Kappa::default());
+    use_lambda(Lambda::default());
+    use_mu(Mu::default());
+    use_nu(Nu::default(), Xi::Foo { foo: 1 });
+    use_nu(Nu::default(), Xi::Bar { foo: 1 });
+    use_omicron(Omicron::default());
+    use_pi(Pi::default());
+    use_rho(Rho::default());
+    use_sigma(sigma::Sigma::default());
+    use_tau(Tau::default());
+    use_upsilon(Upsilon::default());
+    use_phi();
+    use_chi(chi::Chi::default());
+    use_psi(psi::Psi::default());
+    use_omega(Omega::default());
+    use_digamma(Digamma {
+        foo:   0,
+        bar:   ("", ['a', 'b']),
+        qux:   core::num::NonZeroU8::MIN,
+        corge: String::new(),
+    });
 }